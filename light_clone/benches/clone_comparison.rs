@@ -10,7 +10,7 @@
 //!
 //! ```sh
 //! cargo bench -p light_clone              # Core benchmarks only
-//! cargo bench -p light_clone --all-features  # Full comparison (im, imbl, rpds vs std)
+//! cargo bench -p light_clone --all-features  # Full comparison (im, im-rc, imbl, rpds vs std)
 //! ```
 //!
 //! # Benchmark Organization
@@ -18,8 +18,8 @@
 //! Benchmarks are organized by what they test:
 //!
 //! - `arc_*` - Arc overhead verification (always available)
-//! - `collection__*` - Vector clone/mutate comparing std, im, imbl, rpds
-//! - `map__*` - Map clone/mutate comparing std, im, imbl, rpds
+//! - `collection__*` - Vector clone/mutate comparing std, im, im-rc, imbl, rpds
+//! - `map__*` - Map clone/mutate comparing std, im, im-rc, imbl, rpds
 //!
 //! Each collection/map category has two variants:
 //! - `__clone` - Pure clone performance
@@ -33,6 +33,7 @@
 //! |---------|---------|-------------|----------|
 //! | std | (always) | `Vec<T>` | `HashMap<K,V>` |
 //! | im | `im` | `im::Vector<T>` | `im::HashMap<K,V>` |
+//! | im-rc | `im-rc` | `im_rc::Vector<T>` | `im_rc::HashMap<K,V>` |
 //! | imbl | `imbl` | `imbl::Vector<T>` | `imbl::HashMap<K,V>` |
 //! | rpds | `rpds` | `rpds::Vector<T>` | `rpds::HashTrieMap<K,V>` |
 //!
@@ -63,6 +64,11 @@
 //! Key insight: Persistent collections have **constant** clone cost regardless of size,
 //! while std collections grow **linearly** with size.
 //!
+//! The `__by_element_len` variants hold the collection size fixed and vary the length of
+//! the elements. Mutating a clone copies the modified chunk, so `String` elements are
+//! deep-copied while `LightStr` elements only bump refcounts. Only the `LightStr`
+//! collections are `LightCloneDeep`.
+//!
 //! ## Clone vs Mutation Benchmarks
 //!
 //! These benchmarks compare cloning approaches against pure mutation when you
@@ -746,16 +752,17 @@ fn bench_hashmap_clone_vs_mutate(c: &mut Criterion) {
 }
 
 // =============================================================================
-// Feature-gated benchmarks for persistent collections (im, imbl, rpds)
+// Feature-gated benchmarks for persistent collections (im, im-rc, imbl, rpds)
 // =============================================================================
 
-/// Benchmarks comparing persistent collection libraries (im, imbl, rpds) against std.
+/// Benchmarks comparing persistent collection libraries (im, im-rc, imbl, rpds) against std.
 ///
 /// These benchmarks require at least one persistent collection feature to be enabled.
 /// Run with `--all-features` to see all libraries compared side-by-side.
-#[cfg(any(feature = "im", feature = "imbl", feature = "rpds"))]
+#[cfg(any(feature = "im", feature = "im-rc", feature = "imbl", feature = "rpds"))]
 mod persistent_benchmarks {
     use super::*;
+    use light_clone::{IntoLightStr, LightStr};
     use std::collections::HashMap;
 
     // =========================================================================
@@ -788,6 +795,17 @@ mod persistent_benchmarks {
                 });
             }
 
+            // im_rc::Vector
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_vec: im_rc::Vector<i32> = (0..size).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_vector", size),
+                    &im_rc_vec,
+                    |b, v| b.iter(|| black_box(v.light_clone())),
+                );
+            }
+
             // imbl::Vector
             #[cfg(feature = "imbl")]
             {
@@ -843,6 +861,23 @@ mod persistent_benchmarks {
                 });
             }
 
+            // im_rc::Vector
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_vec: im_rc::Vector<i32> = (0..size).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_vector", size),
+                    &im_rc_vec,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).light_clone();
+                            cloned.push_back(999);
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
             // imbl::Vector
             #[cfg(feature = "imbl")]
             {
@@ -872,6 +907,140 @@ mod persistent_benchmarks {
         group.finish();
     }
 
+    /// Benchmark: Collection clone-then-mutate by element type
+    ///
+    /// The clone is O(1) either way, but the mutation copies the modified chunk, so
+    /// `String` elements are deep-copied while `LightStr` elements only bump refcounts.
+    /// Only the `LightStr` variants are `LightCloneDeep`.
+    ///
+    /// Expected:
+    /// - `LightStr` elements: ~100-200ns regardless of string length
+    /// - `String` elements: grows with string length (one allocation per copied element)
+    pub fn bench_collection_clone_then_mutate_by_element(c: &mut Criterion) {
+        let lengths = [8, 64, 512];
+        let size = 1_000;
+
+        let mut group = c.benchmark_group("collection__clone_then_mutate_by_element_len");
+
+        for len in lengths {
+            let strings: Vec<String> = (0..size).map(|i| format!("{i:0>len$}")).collect();
+
+            #[cfg(feature = "im")]
+            {
+                let heavy: im::Vector<String> = strings.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_vector_string", len),
+                    &heavy,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).clone();
+                            cloned.push_back(String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: im::Vector<LightStr> =
+                    strings.iter().map(|s| s.into_light_str()).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_vector_light_str", len),
+                    &light,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).light_clone();
+                            cloned.push_back(LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "im-rc")]
+            {
+                let heavy: im_rc::Vector<String> = strings.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_vector_string", len),
+                    &heavy,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).clone();
+                            cloned.push_back(String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: im_rc::Vector<LightStr> =
+                    strings.iter().map(|s| s.into_light_str()).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_vector_light_str", len),
+                    &light,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).light_clone();
+                            cloned.push_back(LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "imbl")]
+            {
+                let heavy: imbl::Vector<String> = strings.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("imbl_vector_string", len),
+                    &heavy,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).clone();
+                            cloned.push_back(String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: imbl::Vector<LightStr> =
+                    strings.iter().map(|s| s.into_light_str()).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("imbl_vector_light_str", len),
+                    &light,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = black_box(v).light_clone();
+                            cloned.push_back(LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "rpds")]
+            {
+                let heavy: rpds::Vector<String> = strings.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("rpds_vector_string", len),
+                    &heavy,
+                    |b, v| b.iter(|| black_box(black_box(v).clone().push_back(String::new()))),
+                );
+
+                let light: rpds::Vector<LightStr> =
+                    strings.iter().map(|s| s.into_light_str()).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("rpds_vector_light_str", len),
+                    &light,
+                    |b, v| {
+                        b.iter(|| {
+                            black_box(black_box(v).light_clone().push_back(LightStr::from("")))
+                        })
+                    },
+                );
+            }
+        }
+
+        group.finish();
+    }
+
     // =========================================================================
     // Map benchmarks (clone and clone-then-mutate)
     // =========================================================================
@@ -900,6 +1069,17 @@ mod persistent_benchmarks {
                 });
             }
 
+            // im_rc::HashMap
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_map: im_rc::HashMap<i32, i32> = (0..size).map(|i| (i, i * 2)).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_hashmap", size),
+                    &im_rc_map,
+                    |b, map| b.iter(|| black_box(map.light_clone())),
+                );
+            }
+
             // imbl::HashMap
             #[cfg(feature = "imbl")]
             {
@@ -960,6 +1140,23 @@ mod persistent_benchmarks {
                 });
             }
 
+            // im_rc::HashMap
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_map: im_rc::HashMap<i32, i32> = (0..size).map(|i| (i, i * 2)).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_hashmap", size),
+                    &im_rc_map,
+                    |b, map| {
+                        b.iter(|| {
+                            let mut cloned = black_box(map).light_clone();
+                            cloned.insert(99999, 99999);
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
             // imbl::HashMap
             #[cfg(feature = "imbl")]
             {
@@ -1046,6 +1243,23 @@ mod persistent_benchmarks {
                 );
             }
 
+            // im_rc::Vector clone-then-mutate
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_vec: im_rc::Vector<i32> = (0..size).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_vector_clone_mutate", size),
+                    &im_rc_vec,
+                    |b, v| {
+                        b.iter(|| {
+                            let mut cloned = v.light_clone();
+                            cloned.push_back(999);
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
             // imbl::Vector clone-then-mutate
             #[cfg(feature = "imbl")]
             {
@@ -1130,6 +1344,23 @@ mod persistent_benchmarks {
                 );
             }
 
+            // im_rc::HashMap clone-then-mutate
+            #[cfg(feature = "im-rc")]
+            {
+                let im_rc_map: im_rc::HashMap<i32, i32> = (0..size).map(|i| (i, i * 2)).collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_hashmap_clone_mutate", size),
+                    &im_rc_map,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = m.light_clone();
+                            cloned.insert(99999, 99999);
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
             // imbl::HashMap clone-then-mutate
             #[cfg(feature = "imbl")]
             {
@@ -1166,13 +1397,152 @@ mod persistent_benchmarks {
 
         group.finish();
     }
+
+    /// Benchmark: Persistent map clone-then-mutate by value type
+    ///
+    /// Like `bench_collection_clone_then_mutate_by_element`, but for maps: an insert into
+    /// a clone copies the nodes on the path, values included. Only the `LightStr` variants
+    /// are `LightCloneDeep`.
+    pub fn bench_persistent_map_clone_vs_mutate_by_element(c: &mut Criterion) {
+        let lengths = [8, 64, 512];
+        let size = 1_000;
+
+        let mut group = c.benchmark_group("persistent_map__clone_vs_mutate_by_element_len");
+
+        for len in lengths {
+            let entries: Vec<(i32, String)> =
+                (0..size).map(|i| (i, format!("{i:0>len$}"))).collect();
+
+            #[cfg(feature = "im")]
+            {
+                let heavy: im::HashMap<i32, String> = entries.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_hashmap_string", len),
+                    &heavy,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).clone();
+                            cloned.insert(99999, String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: im::HashMap<i32, LightStr> = entries
+                    .iter()
+                    .map(|(k, v)| (*k, v.into_light_str()))
+                    .collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_hashmap_light_str", len),
+                    &light,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).light_clone();
+                            cloned.insert(99999, LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "im-rc")]
+            {
+                let heavy: im_rc::HashMap<i32, String> = entries.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_hashmap_string", len),
+                    &heavy,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).clone();
+                            cloned.insert(99999, String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: im_rc::HashMap<i32, LightStr> = entries
+                    .iter()
+                    .map(|(k, v)| (*k, v.into_light_str()))
+                    .collect();
+                group.bench_with_input(
+                    BenchmarkId::new("im_rc_hashmap_light_str", len),
+                    &light,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).light_clone();
+                            cloned.insert(99999, LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "imbl")]
+            {
+                let heavy: imbl::HashMap<i32, String> = entries.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("imbl_hashmap_string", len),
+                    &heavy,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).clone();
+                            cloned.insert(99999, String::new());
+                            black_box(cloned)
+                        })
+                    },
+                );
+
+                let light: imbl::HashMap<i32, LightStr> = entries
+                    .iter()
+                    .map(|(k, v)| (*k, v.into_light_str()))
+                    .collect();
+                group.bench_with_input(
+                    BenchmarkId::new("imbl_hashmap_light_str", len),
+                    &light,
+                    |b, m| {
+                        b.iter(|| {
+                            let mut cloned = black_box(m).light_clone();
+                            cloned.insert(99999, LightStr::from(""));
+                            black_box(cloned)
+                        })
+                    },
+                );
+            }
+
+            #[cfg(feature = "rpds")]
+            {
+                let heavy: rpds::HashTrieMap<i32, String> = entries.iter().cloned().collect();
+                group.bench_with_input(
+                    BenchmarkId::new("rpds_hashtriemap_string", len),
+                    &heavy,
+                    |b, m| b.iter(|| black_box(black_box(m).clone().insert(99999, String::new()))),
+                );
+
+                let light: rpds::HashTrieMap<i32, LightStr> = entries
+                    .iter()
+                    .map(|(k, v)| (*k, v.into_light_str()))
+                    .collect();
+                group.bench_with_input(
+                    BenchmarkId::new("rpds_hashtriemap_light_str", len),
+                    &light,
+                    |b, m| {
+                        b.iter(|| {
+                            black_box(black_box(m).light_clone().insert(99999, LightStr::from("")))
+                        })
+                    },
+                );
+            }
+        }
+
+        group.finish();
+    }
 }
 
 // =============================================================================
 // Criterion setup - conditionally include persistent collection benchmarks
 // =============================================================================
 
-#[cfg(not(any(feature = "im", feature = "imbl", feature = "rpds")))]
+#[cfg(not(any(feature = "im", feature = "im-rc", feature = "imbl", feature = "rpds")))]
 criterion_group!(
     benches,
     bench_arc_str,
@@ -1191,7 +1561,7 @@ criterion_group!(
     bench_hashmap_clone_vs_mutate,
 );
 
-#[cfg(any(feature = "im", feature = "imbl", feature = "rpds"))]
+#[cfg(any(feature = "im", feature = "im-rc", feature = "imbl", feature = "rpds"))]
 criterion_group!(
     benches,
     bench_arc_str,
@@ -1208,15 +1578,17 @@ criterion_group!(
     bench_vec_clone_vs_mutate,
     bench_struct_clone_vs_mutate,
     bench_hashmap_clone_vs_mutate,
-    // Collection benchmarks (comparing im, imbl, rpds, std)
+    // Collection benchmarks (comparing im, im-rc, imbl, rpds, std)
     persistent_benchmarks::bench_collection_clone,
     persistent_benchmarks::bench_collection_clone_then_mutate,
-    // Map benchmarks (comparing im, imbl, rpds, std)
+    persistent_benchmarks::bench_collection_clone_then_mutate_by_element,
+    // Map benchmarks (comparing im, im-rc, imbl, rpds, std)
     persistent_benchmarks::bench_map_clone,
     persistent_benchmarks::bench_map_clone_then_mutate,
     // Persistent collection clone vs std mutation benchmarks
     persistent_benchmarks::bench_persistent_vec_clone_vs_mutate,
     persistent_benchmarks::bench_persistent_map_clone_vs_mutate,
+    persistent_benchmarks::bench_persistent_map_clone_vs_mutate_by_element,
 );
 
 criterion_main!(benches);
@@ -1,35 +1,83 @@
+use crate::LightSlice;
+use std::ffi::OsStr;
+use std::path::Path;
 use std::sync::Arc;
 
-/// An immutable, cheaply-cloneable string.
-///
-/// `LightStr` is a type alias for `Arc<str>`, providing a string type that is O(1) to clone.
-/// Unlike `String`, cloning a `LightStr` only bumps a reference count—it never allocates
-/// or copies string data.
+/// A cheaply-cloneable byte buffer: a [`LightSlice`] of `u8`.
 ///
 /// # Examples
 ///
 /// ```
-/// use light_clone::{LightStr, IntoLightStr};
+/// use light_clone::{IntoLightSlice, LightBytes, LightClone};
 ///
-/// // Create from a string literal
-/// let name: LightStr = "Alice".into_light_str();
+/// let packet: LightBytes = b"\x01\x02payload".into_light_slice();
+/// let header = packet.slice(..2);
+/// let body = packet.slice(2..);
 ///
-/// // Clone is O(1) - just bumps refcount
-/// let name_copy = name.clone();
-/// assert_eq!(name, name_copy);
+/// assert_eq!(header, [1, 2]);
+/// assert_eq!(&*body, b"payload");
+/// let _copy = packet.light_clone(); // O(1)
 /// ```
+pub type LightBytes = LightSlice<u8>;
+
+/// A cheaply-cloneable filesystem path.
+///
+/// Use it instead of `PathBuf` in types that are passed between threads or stored in
+/// many places. See [`IntoLightPath`](crate::IntoLightPath) for conversions and
+/// [`LightPathExt`] for building new paths.
+///
+/// # Examples
 ///
-/// # Underlying Type
+/// ```
+/// use light_clone::{IntoLightPath, LightClone, LightPath};
 ///
-/// `LightStr` is defined as `Arc<str>`. You can use either type interchangeably:
+/// #[derive(Clone, LightClone)]
+/// struct SourceFile {
+///     path: LightPath,
+/// }
 ///
+/// let file = SourceFile { path: "src/lib.rs".into_light_path() };
+/// let copy = file.light_clone(); // O(1)
+/// assert_eq!(copy.path.extension().unwrap(), "rs");
 /// ```
-/// use std::sync::Arc;
-/// use light_clone::LightStr;
+pub type LightPath = Arc<Path>;
+
+/// A cheaply-cloneable OS string, such as an environment variable or a file name.
+///
+/// See [`IntoLightOsStr`](crate::IntoLightOsStr) for conversions.
+pub type LightOsStr = Arc<OsStr>;
+
+/// Path helpers that return a new [`LightPath`] instead of a `PathBuf` or a borrow.
+///
+/// Implemented for `Path`, so the methods are available on `LightPath`, `&Path` and
+/// `PathBuf` alike.
+///
+/// # Examples
 ///
-/// fn accepts_arc_str(s: &Arc<str>) {}
+/// ```
+/// use light_clone::{IntoLightPath, LightPath, LightPathExt};
 ///
-/// let name: LightStr = Arc::from("Bob");
-/// accepts_arc_str(&name);
+/// let root: LightPath = "/srv/app".into_light_path();
+/// let config = root.light_join("config.toml");
+/// assert_eq!(config.to_str(), Some("/srv/app/config.toml"));
+/// assert_eq!(config.light_parent(), Some(root));
 /// ```
-pub type LightStr = Arc<str>;
+pub trait LightPathExt {
+    /// Like [`Path::join`], returning a [`LightPath`].
+    #[must_use]
+    fn light_join(&self, path: impl AsRef<Path>) -> LightPath;
+
+    /// Like [`Path::parent`], returning an owned [`LightPath`].
+    #[must_use]
+    fn light_parent(&self) -> Option<LightPath>;
+}
+
+impl LightPathExt for Path {
+    fn light_join(&self, path: impl AsRef<Path>) -> LightPath {
+        Arc::from(self.join(path))
+    }
+
+    fn light_parent(&self) -> Option<LightPath> {
+        self.parent().map(Arc::from)
+    }
+}
@@ -1,4 +1,9 @@
-use crate::aliases::LightStr;
+use crate::{LightOsStr, LightPath, LightSlice, LightStr};
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::sync::Arc;
 
 /// Ergonomic conversion trait for creating [`LightStr`] values.
@@ -20,11 +25,14 @@ use std::sync::Arc;
 /// let s2 = owned.into_light_str();
 /// ```
 ///
-/// # Why not just use `Into<Arc<str>>`?
+/// # Why not just use `Into<LightStr>`?
 ///
-/// While `Arc::from()` works fine, `into_light_str()` makes the intent explicit at the call site:
+/// While `LightStr::from()` works fine, `into_light_str()` makes the intent explicit at the call site:
 /// you're converting to a light-clone-able string type. This helps with code readability
 /// and signals that the resulting value is cheap to clone.
+///
+/// For string literals, prefer the `const fn` [`LightStr::from_static`], which never
+/// allocates or copies.
 pub trait IntoLightStr {
     /// Converts this value into a [`LightStr`].
     #[must_use]
@@ -34,7 +42,8 @@ pub trait IntoLightStr {
 impl IntoLightStr for &str {
     /// Converts a string slice into a [`LightStr`].
     ///
-    /// This allocates a new `Arc<str>` containing a copy of the string data.
+    /// Strings of up to 22 bytes are copied inline; longer ones are copied into a new
+    /// `Arc<str>`.
     ///
     /// # Examples
     ///
@@ -45,14 +54,14 @@ impl IntoLightStr for &str {
     /// assert_eq!(&*s, "hello");
     /// ```
     fn into_light_str(self) -> LightStr {
-        Arc::from(self)
+        LightStr::new(self)
     }
 }
 
 impl IntoLightStr for String {
     /// Converts an owned `String` into a [`LightStr`].
     ///
-    /// This consumes the `String` and creates an `Arc<str>` from it.
+    /// Short strings are stored inline; longer ones are moved into an `Arc<str>`.
     ///
     /// # Examples
     ///
@@ -64,14 +73,14 @@ impl IntoLightStr for String {
     /// assert_eq!(&*s, "hello");
     /// ```
     fn into_light_str(self) -> LightStr {
-        Arc::from(self)
+        LightStr::from(self)
     }
 }
 
 impl IntoLightStr for &String {
     /// Converts a reference to a `String` into a [`LightStr`].
     ///
-    /// This allocates a new `Arc<str>` containing a copy of the string data.
+    /// Like `&str`, this copies the string data, inline if it is short enough.
     ///
     /// # Examples
     ///
@@ -83,7 +92,7 @@ impl IntoLightStr for &String {
     /// assert_eq!(&*s, "hello");
     /// ```
     fn into_light_str(self) -> LightStr {
-        Arc::from(self.as_str())
+        LightStr::new(self)
     }
 }
 
@@ -97,13 +106,12 @@ impl IntoLightStr for LightStr {
     ///
     /// ```
     /// use light_clone::{IntoLightStr, LightStr};
-    /// use std::sync::Arc;
     ///
     /// fn takes_into_light_str(s: impl IntoLightStr) -> LightStr {
     ///     s.into_light_str()
     /// }
     ///
-    /// let s: LightStr = Arc::from("hello");
+    /// let s = LightStr::from_static("hello");
     /// let s2 = takes_into_light_str(s.clone());
     /// assert_eq!(&*s2, "hello");
     /// ```
@@ -111,3 +119,334 @@ impl IntoLightStr for LightStr {
         self
     }
 }
+
+impl IntoLightStr for Arc<str> {
+    /// Wraps an existing `Arc<str>` in a [`LightStr`] without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::IntoLightStr;
+    /// use std::sync::Arc;
+    ///
+    /// let shared: Arc<str> = Arc::from("hello");
+    /// let s = shared.clone().into_light_str();
+    /// assert_eq!(s, "hello");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        LightStr::from(self)
+    }
+}
+
+impl IntoLightStr for &&str {
+    /// Converts a doubly-referenced string slice into a [`LightStr`], as produced by
+    /// iterating over a `&[&str]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::{IntoLightStr, LightStr};
+    ///
+    /// let words = ["a", "b"];
+    /// let light: Vec<LightStr> = words.iter().map(|w| w.into_light_str()).collect();
+    /// assert_eq!(light, ["a", "b"]);
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        LightStr::new(self)
+    }
+}
+
+impl IntoLightStr for Cow<'_, str> {
+    /// Converts a `Cow<str>` into a [`LightStr`], like `&str` or `String` depending on
+    /// the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::IntoLightStr;
+    ///
+    /// let s = String::from_utf8_lossy(b"hello").into_light_str();
+    /// assert_eq!(s, "hello");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        match self {
+            Cow::Borrowed(s) => LightStr::new(s),
+            Cow::Owned(s) => LightStr::from(s),
+        }
+    }
+}
+
+impl IntoLightStr for Box<str> {
+    /// Converts a boxed string into a [`LightStr`], like `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::IntoLightStr;
+    ///
+    /// let boxed: Box<str> = "hello".into();
+    /// assert_eq!(boxed.into_light_str(), "hello");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        LightStr::from(self.into_string())
+    }
+}
+
+impl IntoLightStr for Rc<str> {
+    /// Converts an `Rc<str>` into a [`LightStr`].
+    ///
+    /// `Rc` can't be shared across threads, so this copies the string data like `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::IntoLightStr;
+    /// use std::rc::Rc;
+    ///
+    /// let shared: Rc<str> = Rc::from("hello");
+    /// assert_eq!(shared.into_light_str(), "hello");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        LightStr::new(&self)
+    }
+}
+
+impl IntoLightStr for char {
+    /// Converts a character into a [`LightStr`], always stored inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::IntoLightStr;
+    ///
+    /// assert_eq!('x'.into_light_str(), "x");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        LightStr::new(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+/// Converts any [`Display`] value into a [`LightStr`], the `LightStr` counterpart of
+/// `ToString`.
+///
+/// Formats through [`LightStr::from_fmt`], so short results don't allocate and long ones
+/// allocate a single `Arc<str>` instead of a `String` plus a copy.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::ToLightStr;
+///
+/// let port = 8080.to_light_str();
+/// assert_eq!(port, "8080");
+/// ```
+///
+/// Calling this on a [`LightStr`] copies it like any other `Display` type; use
+/// `light_clone()` to share it instead.
+pub trait ToLightStr {
+    /// Formats this value into a [`LightStr`].
+    #[must_use]
+    fn to_light_str(&self) -> LightStr;
+}
+
+impl<T: Display + ?Sized> ToLightStr for T {
+    fn to_light_str(&self) -> LightStr {
+        LightStr::from_fmt(format_args!("{self}"))
+    }
+}
+
+/// Ergonomic conversion trait for creating [`LightSlice`] values, the slice counterpart
+/// of [`IntoLightStr`].
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLightSlice, LightSlice};
+///
+/// fn store(ids: impl IntoLightSlice<u64>) -> LightSlice<u64> {
+///     ids.into_light_slice()
+/// }
+///
+/// let from_vec = store(vec![1, 2, 3]);
+/// let from_slice = store(&[1, 2, 3][..]);
+/// let from_array = store([1, 2, 3]);
+/// assert_eq!(from_vec, from_slice);
+/// assert_eq!(from_slice, from_array);
+/// ```
+pub trait IntoLightSlice<T> {
+    /// Converts this value into a [`LightSlice`].
+    #[must_use]
+    fn into_light_slice(self) -> LightSlice<T>;
+}
+
+impl<T> IntoLightSlice<T> for Vec<T> {
+    /// Moves the elements into a new `Arc<[T]>`.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self)
+    }
+}
+
+impl<T: Clone> IntoLightSlice<T> for &[T] {
+    /// Clones the elements into a new `Arc<[T]>`.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self)
+    }
+}
+
+impl<T: Clone> IntoLightSlice<T> for &Vec<T> {
+    /// Clones the elements into a new `Arc<[T]>`.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self.as_slice())
+    }
+}
+
+impl<T, const N: usize> IntoLightSlice<T> for [T; N] {
+    /// Moves the elements into a new `Arc<[T]>` with a single allocation.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self)
+    }
+}
+
+impl<T: Clone, const N: usize> IntoLightSlice<T> for &[T; N] {
+    /// Clones the elements into a new `Arc<[T]>`.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self.as_slice())
+    }
+}
+
+impl<T> IntoLightSlice<T> for Box<[T]> {
+    /// Moves the elements into a new `Arc<[T]>`.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self)
+    }
+}
+
+impl<T> IntoLightSlice<T> for Arc<[T]> {
+    /// Wraps the existing `Arc<[T]>` without copying.
+    fn into_light_slice(self) -> LightSlice<T> {
+        LightSlice::from(self)
+    }
+}
+
+impl<T> IntoLightSlice<T> for LightSlice<T> {
+    /// Returns the [`LightSlice`] unchanged.
+    fn into_light_slice(self) -> LightSlice<T> {
+        self
+    }
+}
+
+/// Ergonomic conversion trait for creating [`LightPath`] values.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLightPath, LightPath};
+/// use std::path::{Path, PathBuf};
+///
+/// fn watch(path: impl IntoLightPath) -> LightPath {
+///     path.into_light_path()
+/// }
+///
+/// let a = watch("src/main.rs");
+/// let b = watch(PathBuf::from("src/main.rs"));
+/// let c = watch(Path::new("src/main.rs"));
+/// assert_eq!(a, b);
+/// assert_eq!(b, c);
+/// ```
+pub trait IntoLightPath {
+    /// Converts this value into a [`LightPath`].
+    #[must_use]
+    fn into_light_path(self) -> LightPath;
+}
+
+impl IntoLightPath for &Path {
+    fn into_light_path(self) -> LightPath {
+        Arc::from(self)
+    }
+}
+
+impl IntoLightPath for PathBuf {
+    fn into_light_path(self) -> LightPath {
+        Arc::from(self)
+    }
+}
+
+impl IntoLightPath for &PathBuf {
+    fn into_light_path(self) -> LightPath {
+        Arc::from(self.as_path())
+    }
+}
+
+impl IntoLightPath for &str {
+    fn into_light_path(self) -> LightPath {
+        Arc::from(Path::new(self))
+    }
+}
+
+impl IntoLightPath for String {
+    fn into_light_path(self) -> LightPath {
+        Arc::from(PathBuf::from(self))
+    }
+}
+
+impl IntoLightPath for LightPath {
+    /// Returns the [`LightPath`] unchanged.
+    fn into_light_path(self) -> LightPath {
+        self
+    }
+}
+
+/// Ergonomic conversion trait for creating [`LightOsStr`] values.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLightOsStr, LightOsStr};
+/// use std::ffi::OsString;
+///
+/// let from_str: LightOsStr = "PATH".into_light_os_str();
+/// let from_os_string = OsString::from("PATH").into_light_os_str();
+/// assert_eq!(from_str, from_os_string);
+/// ```
+pub trait IntoLightOsStr {
+    /// Converts this value into a [`LightOsStr`].
+    #[must_use]
+    fn into_light_os_str(self) -> LightOsStr;
+}
+
+impl IntoLightOsStr for &OsStr {
+    fn into_light_os_str(self) -> LightOsStr {
+        Arc::from(self)
+    }
+}
+
+impl IntoLightOsStr for OsString {
+    fn into_light_os_str(self) -> LightOsStr {
+        Arc::from(self)
+    }
+}
+
+impl IntoLightOsStr for &OsString {
+    fn into_light_os_str(self) -> LightOsStr {
+        Arc::from(self.as_os_str())
+    }
+}
+
+impl IntoLightOsStr for &str {
+    fn into_light_os_str(self) -> LightOsStr {
+        Arc::from(OsStr::new(self))
+    }
+}
+
+impl IntoLightOsStr for String {
+    fn into_light_os_str(self) -> LightOsStr {
+        Arc::from(OsString::from(self))
+    }
+}
+
+impl IntoLightOsStr for LightOsStr {
+    /// Returns the [`LightOsStr`] unchanged.
+    fn into_light_os_str(self) -> LightOsStr {
+        self
+    }
+}
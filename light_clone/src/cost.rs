@@ -0,0 +1,205 @@
+use std::fmt;
+
+/// The largest bitwise copy, in bytes, that counts as a light clone.
+///
+/// `[T; N]` arrays and derived `Copy`-tier types larger than this fail to compile, since
+/// cloning them copies every byte. The budget defaults to 256 bytes and is read from the
+/// `LIGHT_CLONE_MAX_COPY_SIZE` environment variable when `light_clone` is built, e.g.
+/// from `.cargo/config.toml`:
+///
+/// ```toml
+/// [env]
+/// LIGHT_CLONE_MAX_COPY_SIZE = "1024"
+/// ```
+///
+/// A single derived type can replace the budget with `#[light_clone(max_size = N)]`.
+pub const MAX_COPY_SIZE: usize = parse_max_copy_size(option_env!("LIGHT_CLONE_MAX_COPY_SIZE"));
+
+const DEFAULT_MAX_COPY_SIZE: usize = 256;
+
+const fn parse_max_copy_size(value: Option<&str>) -> usize {
+    let bytes = match value {
+        Some(value) => value.as_bytes(),
+        None => return DEFAULT_MAX_COPY_SIZE,
+    };
+    if bytes.is_empty() {
+        panic!("LIGHT_CLONE_MAX_COPY_SIZE must be a number of bytes");
+    }
+
+    let mut size: usize = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            panic!("LIGHT_CLONE_MAX_COPY_SIZE must be a number of bytes");
+        }
+        size = match size.checked_mul(10) {
+            Some(size) => size,
+            None => panic!("LIGHT_CLONE_MAX_COPY_SIZE is too large"),
+        };
+        size = match size.checked_add((bytes[i] - b'0') as usize) {
+            Some(size) => size,
+            None => panic!("LIGHT_CLONE_MAX_COPY_SIZE is too large"),
+        };
+        i += 1;
+    }
+    size
+}
+
+/// The kind of work a [`LightClone`](crate::LightClone) clone does, from cheapest to
+/// most expensive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CostTier {
+    /// Bitwise copy (`Copy` types).
+    Copy,
+    /// Non-atomic refcount increments (`Rc`).
+    NonAtomic,
+    /// Atomic refcount increments (`Arc`).
+    Atomic,
+    /// Persistent data structure clone (`im`, `imbl`, `rpds`).
+    Persistent,
+    /// Not classified, e.g. a manual `LightClone` impl that doesn't set `COST`.
+    Unknown,
+}
+
+impl CostTier {
+    const fn rank(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for CostTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CostTier::Copy => "copy",
+            CostTier::NonAtomic => "non-atomic refcount",
+            CostTier::Atomic => "atomic refcount",
+            CostTier::Persistent => "persistent",
+            CostTier::Unknown => "unknown",
+        })
+    }
+}
+
+/// Compile-time classification of how expensive a light clone is.
+///
+/// Every [`LightClone`](crate::LightClone) type exposes its cost as
+/// [`LightClone::COST`](crate::LightClone::COST). A cost is the most expensive
+/// [`CostTier`] involved plus the number of refcount increments a clone performs.
+/// Persistent collections count one increment for their root.
+///
+/// Costs order by tier first, then by refcount increments, and can be checked in tests
+/// or at compile time:
+///
+/// ```
+/// use light_clone::{CloneCost, CostTier, LightClone};
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, LightClone)]
+/// struct Message {
+///     id: u64,
+///     topic: Arc<str>,
+///     payload: Arc<[u8]>,
+/// }
+///
+/// assert_eq!(Message::COST.tier(), CostTier::Atomic);
+/// assert_eq!(Message::COST.refcounts(), 2);
+/// const _: () = assert!(Message::COST.fits_within(CloneCost::new(CostTier::Atomic, 2)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CloneCost {
+    tier: CostTier,
+    refcounts: u32,
+}
+
+impl CloneCost {
+    /// A bitwise copy.
+    pub const COPY: CloneCost = CloneCost::new(CostTier::Copy, 0);
+
+    /// A single non-atomic refcount increment.
+    pub const NON_ATOMIC: CloneCost = CloneCost::new(CostTier::NonAtomic, 1);
+
+    /// A single atomic refcount increment.
+    pub const ATOMIC: CloneCost = CloneCost::new(CostTier::Atomic, 1);
+
+    /// A persistent data structure clone, counted as one increment of its root.
+    pub const PERSISTENT: CloneCost = CloneCost::new(CostTier::Persistent, 1);
+
+    /// The cost of a type that doesn't classify itself.
+    pub const UNKNOWN: CloneCost = CloneCost::new(CostTier::Unknown, 0);
+
+    /// Creates a cost from a tier and a number of refcount increments.
+    pub const fn new(tier: CostTier, refcounts: u32) -> Self {
+        CloneCost { tier, refcounts }
+    }
+
+    /// Returns [`CloneCost::COPY`] for a bitwise copy of `T`, failing to compile if `T`
+    /// is larger than [`MAX_COPY_SIZE`].
+    ///
+    /// The check runs when the cost is evaluated, e.g. by a manual impl:
+    ///
+    /// ```
+    /// use light_clone::{CloneCost, LightClone};
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct Rgba([u8; 4]);
+    ///
+    /// impl LightClone for Rgba {
+    ///     const COST: CloneCost = CloneCost::copy_of::<Self>();
+    /// }
+    /// ```
+    pub const fn copy_of<T>() -> CloneCost {
+        if std::mem::size_of::<T>() > MAX_COPY_SIZE {
+            panic!(
+                "bitwise copy is larger than `light_clone::MAX_COPY_SIZE`; \
+                 store the data behind an `Arc`, or raise the budget with the \
+                 `LIGHT_CLONE_MAX_COPY_SIZE` environment variable"
+            );
+        }
+        CloneCost::COPY
+    }
+
+    /// Returns the most expensive kind of work the clone does.
+    pub const fn tier(self) -> CostTier {
+        self.tier
+    }
+
+    /// Returns the number of refcount increments the clone performs.
+    pub const fn refcounts(self) -> u32 {
+        self.refcounts
+    }
+
+    /// Returns the cost of cloning both values, e.g. two fields of a struct.
+    pub const fn plus(self, other: CloneCost) -> CloneCost {
+        let tier = if other.tier.rank() > self.tier.rank() {
+            other.tier
+        } else {
+            self.tier
+        };
+        CloneCost::new(tier, self.refcounts.saturating_add(other.refcounts))
+    }
+
+    /// Returns the more expensive of two costs, e.g. two variants of an enum.
+    pub const fn max(self, other: CloneCost) -> CloneCost {
+        if other.tier.rank() > self.tier.rank()
+            || (other.tier.rank() == self.tier.rank() && other.refcounts > self.refcounts)
+        {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if this cost is no worse than `limit` in both tier and refcounts.
+    pub const fn fits_within(self, limit: CloneCost) -> bool {
+        self.tier.rank() <= limit.tier.rank() && self.refcounts <= limit.refcounts
+    }
+}
+
+impl fmt::Display for CloneCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.refcounts {
+            0 => write!(f, "{}", self.tier),
+            1 => write!(f, "{} (1 increment)", self.tier),
+            n => write!(f, "{} ({} increments)", self.tier, n),
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! Compile-time assertions emitted by `#[derive(LightClone)]`.
+//!
+//! The derive asserts each concrete field type separately, spanned on the field, so
+//! rustc points at the offending field instead of the derive. When the field's type
+//! is syntactically recognizable (e.g. `String` or `Vec<T>`), the assertion goes
+//! through a hint trait whose diagnostic suggests a light replacement.
+//!
+//! Unions are cloned bitwise, so the derive asserts they are `Copy` instead.
+//!
+//! Every hint trait is blanket-implemented for all `LightClone` types, so it only
+//! changes the error message, never what compiles.
+//!
+//! The assertions are `const fn`s returning the field's [`CloneCost`], so the derive
+//! builds `LightClone::COST` out of them and each field is checked exactly once.
+//!
+//! `#[derive(LightCloneSync)]` asserts its concrete fields the same way, with hints for
+//! the single-threaded types that are `LightClone` but not [`LightCloneSync`], and
+//! `#[derive(LightCopy)]` asserts that they are [`LightCopy`].
+//!
+//! `#[no_heavy_clone]` rewrites `.clone()` calls to [`CheckedClone`], which only
+//! compiles for `LightClone` receivers.
+
+use crate::{CloneCost, CostTier, LightClone, LightCloneSync, LightCopy};
+
+/// Asserts that a field type implements [`LightClone`].
+pub const fn assert_light_clone<T: LightClone>() -> CloneCost {
+    T::COST
+}
+
+/// Applies the [`MAX_COPY_SIZE`](crate::MAX_COPY_SIZE) budget to a derived cost.
+///
+/// Only bitwise copies are checked; types with refcounts are already bounded by their
+/// `Copy`-tier fields.
+pub const fn check_copy_size<T>(cost: CloneCost) -> CloneCost {
+    match cost.tier() {
+        CostTier::Copy => CloneCost::copy_of::<T>(),
+        _ => cost,
+    }
+}
+
+/// Diagnostic hint for unions deriving `LightClone`.
+#[diagnostic::on_unimplemented(
+    message = "`LightClone` can only be derived for unions that are `Copy`",
+    label = "`{Self}` is not `Copy`",
+    note = "add `#[derive(Clone, Copy)]` to the union"
+)]
+pub trait CopyUnionHint {}
+
+impl<T: Copy> CopyUnionHint for T {}
+
+/// Asserts that a union deriving `LightClone` is `Copy`.
+pub const fn assert_copy_union<T: CopyUnionHint>() -> CloneCost {
+    CloneCost::COPY
+}
+
+/// Diagnostic hint for `String` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use `light_clone::LightStr` or `Arc<str>` instead of `String`"
+)]
+pub trait StringHint: LightClone {}
+
+impl<T: LightClone> StringHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_string_field<T: StringHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `PathBuf` and `OsString` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use `light_clone::LightPath` (`Arc<Path>`) instead of `PathBuf`, or `light_clone::LightOsStr` (`Arc<OsStr>`) instead of `OsString`"
+)]
+pub trait PathHint: LightClone {}
+
+impl<T: LightClone> PathHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_path_field<T: PathHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `Vec<T>` and `VecDeque<T>` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use `Arc<[T]>` for immutable data, or a persistent vector such as `im::Vector<T>`"
+)]
+pub trait VecHint: LightClone {}
+
+impl<T: LightClone> VecHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_vec_field<T: VecHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `HashMap` and `BTreeMap` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use a persistent map such as `im::HashMap<K, V>` or `im::OrdMap<K, V>`, or wrap the map in an `Arc`"
+)]
+pub trait MapHint: LightClone {}
+
+impl<T: LightClone> MapHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_map_field<T: MapHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `HashSet` and `BTreeSet` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use a persistent set such as `im::HashSet<T>` or `im::OrdSet<T>`, or wrap the set in an `Arc`"
+)]
+pub trait SetHint: LightClone {}
+
+impl<T: LightClone> SetHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_set_field<T: SetHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `Box<T>` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` deep-copies its contents on clone",
+    label = "`{Self}` is not `LightClone`",
+    note = "use `Arc<T>` instead of `Box<T>` to share the allocation"
+)]
+pub trait BoxHint: LightClone {}
+
+impl<T: LightClone> BoxHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_box_field<T: BoxHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `im::*` collection fields.
+#[cfg_attr(
+    feature = "im",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "check that the element types satisfy the collection's `LightClone` bounds"
+    )
+)]
+#[cfg_attr(
+    not(feature = "im"),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "enable the `im` feature of `light_clone` to use `im` collections"
+    )
+)]
+pub trait ImHint: LightClone {}
+
+impl<T: LightClone> ImHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_im_field<T: ImHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `im_rc::*` collection fields.
+#[cfg_attr(
+    feature = "im-rc",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "check that the element types satisfy the collection's `LightClone` bounds"
+    )
+)]
+#[cfg_attr(
+    not(feature = "im-rc"),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "enable the `im-rc` feature of `light_clone` to use `im-rc` collections"
+    )
+)]
+pub trait ImRcHint: LightClone {}
+
+impl<T: LightClone> ImRcHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_im_rc_field<T: ImRcHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `imbl::*` collection fields.
+#[cfg_attr(
+    feature = "imbl",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "check that the element types satisfy the collection's `LightClone` bounds"
+    )
+)]
+#[cfg_attr(
+    not(feature = "imbl"),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "enable the `imbl` feature of `light_clone` to use `imbl` collections"
+    )
+)]
+pub trait ImblHint: LightClone {}
+
+impl<T: LightClone> ImblHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_imbl_field<T: ImblHint>() -> CloneCost {
+    T::COST
+}
+
+/// Diagnostic hint for `rpds::*` collection fields.
+#[cfg_attr(
+    feature = "rpds",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "check that the element types satisfy the collection's `LightClone` bounds"
+    )
+)]
+#[cfg_attr(
+    not(feature = "rpds"),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `LightClone`",
+        label = "`{Self}` is not `LightClone`",
+        note = "enable the `rpds` feature of `light_clone` to use `rpds` collections"
+    )
+)]
+pub trait RpdsHint: LightClone {}
+
+impl<T: LightClone> RpdsHint for T {}
+
+/// Asserts that a field type implements [`LightClone`], with a targeted hint.
+pub const fn assert_rpds_field<T: RpdsHint>() -> CloneCost {
+    T::COST
+}
+
+/// Asserts that a field type implements [`LightCopy`].
+pub const fn assert_light_copy<T: LightCopy>() {}
+
+/// Asserts that a field type implements [`LightCloneSync`].
+pub const fn assert_light_clone_sync<T: LightCloneSync>() {}
+
+/// Diagnostic hint for `Rc<T>` and `rc::Weak<T>` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` uses a non-atomic refcount and can't be shared across threads",
+    label = "`{Self}` is not `LightCloneSync`",
+    note = "use `Arc<T>` (or `sync::Weak<T>`) instead of `Rc<T>`"
+)]
+pub trait RcSyncHint: LightCloneSync {}
+
+impl<T: LightCloneSync> RcSyncHint for T {}
+
+/// Asserts that a field type implements [`LightCloneSync`], with a targeted hint.
+pub const fn assert_sync_rc_field<T: RcSyncHint>() {}
+
+/// Diagnostic hint for `Cell<T>` and `RefCell<T>` fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has interior mutability and can't be shared across threads",
+    label = "`{Self}` is not `LightCloneSync`",
+    note = "store the value directly, or use an atomic type behind an `Arc`"
+)]
+pub trait CellSyncHint: LightCloneSync {}
+
+impl<T: LightCloneSync> CellSyncHint for T {}
+
+/// Asserts that a field type implements [`LightCloneSync`], with a targeted hint.
+pub const fn assert_sync_cell_field<T: CellSyncHint>() {}
+
+/// Diagnostic hint for `im_rc::*` collection fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` uses non-atomic refcounts and can't be shared across threads",
+    label = "`{Self}` is not `LightCloneSync`",
+    note = "use the `im` crate's `Arc`-based collections instead of `im_rc`"
+)]
+pub trait ImRcSyncHint: LightCloneSync {}
+
+impl<T: LightCloneSync> ImRcSyncHint for T {}
+
+/// Asserts that a field type implements [`LightCloneSync`], with a targeted hint.
+pub const fn assert_sync_im_rc_field<T: ImRcSyncHint>() {}
+
+/// Diagnostic hint for `rpds::*` collection fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `LightCloneSync`",
+    label = "`{Self}` is not `LightCloneSync`",
+    note = "rpds collections are only thread-safe with the `ArcTK` or `ArcK` pointer kind",
+    note = "use the `*Sync` alias, e.g. `rpds::VectorSync<T>` instead of `rpds::Vector<T>`"
+)]
+pub trait RpdsSyncHint: LightCloneSync {}
+
+impl<T: LightCloneSync> RpdsSyncHint for T {}
+
+/// Asserts that a field type implements [`LightCloneSync`], with a targeted hint.
+pub const fn assert_sync_rpds_field<T: RpdsSyncHint>() {}
+
+/// Diagnostic hint for `.clone()` calls rewritten by `#[no_heavy_clone]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is deep-copied by `.clone()` inside `#[no_heavy_clone]`",
+    label = "cloning `{Self}` is not O(1)",
+    note = "use a `LightClone` type, or mark the statement `#[allow_heavy_clone]` if the deep copy is intended"
+)]
+pub trait NoHeavyCloneHint: LightClone {}
+
+impl<T: LightClone> NoHeavyCloneHint for T {}
+
+/// Replacement for `Clone::clone` inside `#[no_heavy_clone]` items.
+///
+/// Implemented for every `Clone` type so method lookup picks the same receiver type as
+/// `.clone()` would, then requires that type to be `LightClone`.
+pub trait CheckedClone: Clone {
+    #[inline]
+    fn __light_clone_checked(&self) -> Self
+    where
+        Self: NoHeavyCloneHint,
+    {
+        self.light_clone()
+    }
+}
+
+impl<T: Clone> CheckedClone for T {}
@@ -8,9 +8,13 @@
 //! Note: `BytesMut` is NOT implemented as it is uniquely owned and
 //! cloning requires copying the underlying data.
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync};
 
-impl LightClone for bytes::Bytes {}
+impl LightClone for bytes::Bytes {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl LightCloneSync for bytes::Bytes {}
 
 #[cfg(test)]
 mod tests {
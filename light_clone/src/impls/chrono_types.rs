@@ -4,13 +4,19 @@
 //!
 //! All chrono date/time types are `Copy`, so cloning is always O(1).
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
 /// Macro to implement LightClone for types.
 macro_rules! impl_light_clone {
     ($($t:ty),* $(,)?) => {
         $(
-            impl LightClone for $t {}
+            impl LightClone for $t {
+                const COST: CloneCost = CloneCost::COPY;
+            }
+
+            impl LightCloneSync for $t {}
+
+            impl LightCopy for $t {}
         )*
     };
 }
@@ -27,7 +33,26 @@ impl_light_clone!(
 );
 
 // DateTime<Tz> is Copy when Tz is Copy
-impl<Tz: chrono::TimeZone + Copy> LightClone for chrono::DateTime<Tz> where Tz::Offset: Copy {}
+impl<Tz: chrono::TimeZone + Copy> LightClone for chrono::DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<Tz> LightCloneSync for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone + Copy + Send + Sync,
+    Tz::Offset: Copy + Send + Sync,
+{
+}
+
+impl<Tz> LightCopy for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone + Copy,
+    Tz::Offset: Copy,
+{
+}
 
 #[cfg(test)]
 mod tests {
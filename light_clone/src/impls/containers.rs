@@ -1,4 +1,4 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 use std::cell::Cell;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
@@ -7,25 +7,82 @@ use std::pin::Pin;
 use std::ptr::NonNull;
 use std::task::Poll;
 
-impl LightClone for () {}
+impl LightClone for () {
+    const COST: CloneCost = CloneCost::COPY;
+}
 
-impl<T> LightClone for PhantomData<T> {}
+impl LightCloneSync for () {}
 
-impl<T: LightClone> LightClone for Option<T> {}
+impl LightCopy for () {}
 
-impl<T: LightClone, E: Clone> LightClone for Result<T, E> {}
+impl<T> LightClone for PhantomData<T> {
+    const COST: CloneCost = CloneCost::COPY;
+}
 
-impl<T: LightClone + Copy, const N: usize> LightClone for [T; N] {}
+impl<T: Send + Sync> LightCloneSync for PhantomData<T> {}
+
+impl<T> LightCopy for PhantomData<T> {}
+
+impl<T: LightClone> LightClone for Option<T> {
+    const COST: CloneCost = T::COST;
+}
+
+impl<T: LightCloneSync> LightCloneSync for Option<T> {}
+
+impl<T: LightCopy> LightCopy for Option<T> {}
+
+impl<T: LightClone, E: LightClone> LightClone for Result<T, E> {
+    const COST: CloneCost = T::COST.max(E::COST);
+}
+
+impl<T: LightCloneSync, E: LightCloneSync> LightCloneSync for Result<T, E> {}
+
+impl<T: LightCopy, E: LightCopy> LightCopy for Result<T, E> {}
+
+// Arrays are copied element by element, so large ones are rejected
+impl<T: LightClone + Copy, const N: usize> LightClone for [T; N] {
+    const COST: CloneCost = CloneCost::copy_of::<Self>();
+}
+
+impl<T: LightCloneSync + Copy, const N: usize> LightCloneSync for [T; N] {}
+
+impl<T: LightCopy, const N: usize> LightCopy for [T; N] {}
 
 // Wrapper types
-impl<T: LightClone> LightClone for Bound<T> {}
+impl<T: LightClone> LightClone for Bound<T> {
+    const COST: CloneCost = T::COST;
+}
+
+impl<T: LightCloneSync> LightCloneSync for Bound<T> {}
+
+impl<T: LightCopy> LightCopy for Bound<T> {}
+
+impl<T: LightClone> LightClone for Pin<T> {
+    const COST: CloneCost = T::COST;
+}
+
+impl<T: LightCloneSync> LightCloneSync for Pin<T> {}
+
+impl<T: ?Sized> LightClone for NonNull<T> {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<T: ?Sized> LightCopy for NonNull<T> {}
+
+impl<T: LightClone> LightClone for Poll<T> {
+    const COST: CloneCost = T::COST;
+}
 
-impl<T: LightClone> LightClone for Pin<T> {}
+impl<T: LightCloneSync> LightCloneSync for Poll<T> {}
 
-impl<T: ?Sized> LightClone for NonNull<T> {}
+impl<T: LightCopy> LightCopy for Poll<T> {}
 
-impl<T: LightClone> LightClone for Poll<T> {}
+impl<T: LightClone + Copy> LightClone for Cell<T> {
+    const COST: CloneCost = CloneCost::copy_of::<Self>();
+}
 
-impl<T: LightClone + Copy> LightClone for Cell<T> {}
+impl<T: LightClone> LightClone for ManuallyDrop<T> {
+    const COST: CloneCost = T::COST;
+}
 
-impl<T: LightClone> LightClone for ManuallyDrop<T> {}
+impl<T: LightCloneSync> LightCloneSync for ManuallyDrop<T> {}
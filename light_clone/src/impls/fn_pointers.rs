@@ -1,11 +1,23 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
 macro_rules! impl_light_clone_for_fn {
     () => {
-        impl<Ret> LightClone for fn() -> Ret {}
+        impl<Ret> LightClone for fn() -> Ret {
+            const COST: CloneCost = CloneCost::COPY;
+        }
+
+        impl<Ret> LightCloneSync for fn() -> Ret {}
+
+        impl<Ret> LightCopy for fn() -> Ret {}
     };
     ($($arg:ident),+) => {
-        impl<Ret, $($arg),+> LightClone for fn($($arg),+) -> Ret {}
+        impl<Ret, $($arg),+> LightClone for fn($($arg),+) -> Ret {
+            const COST: CloneCost = CloneCost::COPY;
+        }
+
+        impl<Ret, $($arg),+> LightCloneSync for fn($($arg),+) -> Ret {}
+
+        impl<Ret, $($arg),+> LightCopy for fn($($arg),+) -> Ret {}
     };
 }
 
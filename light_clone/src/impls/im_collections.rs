@@ -2,27 +2,81 @@
 //!
 //! These implementations are behind the `im` feature flag.
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneDeep, LightCloneSync};
 
-impl<T: Clone> LightClone for im::Vector<T> {}
+impl<T: Clone> LightClone for im::Vector<T> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
 
 impl<K, V> LightClone for im::HashMap<K, V>
 where
     K: Clone + std::hash::Hash + Eq,
     V: Clone,
 {
+    const COST: CloneCost = CloneCost::PERSISTENT;
 }
 
 impl<K, V> LightClone for im::OrdMap<K, V>
 where
     K: Clone + Ord,
     V: Clone,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for im::HashSet<T>
+where
+    T: Clone + std::hash::Hash + Eq,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for im::OrdSet<T>
+where
+    T: Clone + Ord,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: Clone + Send + Sync> LightCloneSync for im::Vector<T> {}
+
+impl<K, V> LightCloneSync for im::HashMap<K, V>
+where
+    K: Clone + std::hash::Hash + Eq + Send + Sync,
+    V: Clone + Send + Sync,
+{
+}
+
+impl<K, V> LightCloneSync for im::OrdMap<K, V>
+where
+    K: Clone + Ord + Send + Sync,
+    V: Clone + Send + Sync,
+{
+}
+
+impl<T> LightCloneSync for im::HashSet<T> where T: Clone + std::hash::Hash + Eq + Send + Sync {}
+
+impl<T> LightCloneSync for im::OrdSet<T> where T: Clone + Ord + Send + Sync {}
+
+impl<T: LightClone> LightCloneDeep for im::Vector<T> {}
+
+impl<K, V> LightCloneDeep for im::HashMap<K, V>
+where
+    K: LightClone + std::hash::Hash + Eq,
+    V: LightClone,
+{
+}
+
+impl<K, V> LightCloneDeep for im::OrdMap<K, V>
+where
+    K: LightClone + Ord,
+    V: LightClone,
 {
 }
 
-impl<T> LightClone for im::HashSet<T> where T: Clone + std::hash::Hash + Eq {}
+impl<T> LightCloneDeep for im::HashSet<T> where T: LightClone + std::hash::Hash + Eq {}
 
-impl<T> LightClone for im::OrdSet<T> where T: Clone + Ord {}
+impl<T> LightCloneDeep for im::OrdSet<T> where T: LightClone + Ord {}
 
 #[cfg(test)]
 mod tests {
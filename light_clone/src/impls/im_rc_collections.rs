@@ -0,0 +1,106 @@
+//! LightClone implementations for the `im-rc` crate's persistent collections.
+//!
+//! These implementations are behind the `im-rc` feature flag.
+//!
+//! `im-rc` is the single-threaded sibling of `im` with the same API. Its collections
+//! use `Rc` internally, so clones bump non-atomic refcounts.
+
+use crate::{CloneCost, LightClone, LightCloneDeep};
+
+impl<T: Clone> LightClone for im_rc::Vector<T> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<K, V> LightClone for im_rc::HashMap<K, V>
+where
+    K: Clone + std::hash::Hash + Eq,
+    V: Clone,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<K, V> LightClone for im_rc::OrdMap<K, V>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for im_rc::HashSet<T>
+where
+    T: Clone + std::hash::Hash + Eq,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for im_rc::OrdSet<T>
+where
+    T: Clone + Ord,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: LightClone> LightCloneDeep for im_rc::Vector<T> {}
+
+impl<K, V> LightCloneDeep for im_rc::HashMap<K, V>
+where
+    K: LightClone + std::hash::Hash + Eq,
+    V: LightClone,
+{
+}
+
+impl<K, V> LightCloneDeep for im_rc::OrdMap<K, V>
+where
+    K: LightClone + Ord,
+    V: LightClone,
+{
+}
+
+impl<T> LightCloneDeep for im_rc::HashSet<T> where T: LightClone + std::hash::Hash + Eq {}
+
+impl<T> LightCloneDeep for im_rc::OrdSet<T> where T: LightClone + Ord {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_implements_light_clone() {
+        let v: im_rc::Vector<i32> = im_rc::vector![1, 2, 3];
+        let cloned = v.light_clone();
+        assert_eq!(v, cloned);
+    }
+
+    #[test]
+    fn hash_map_implements_light_clone() {
+        let mut m = im_rc::HashMap::new();
+        m.insert("key", 42);
+        let cloned = m.light_clone();
+        assert_eq!(m, cloned);
+    }
+
+    #[test]
+    fn ord_map_implements_light_clone() {
+        let mut m = im_rc::OrdMap::new();
+        m.insert("key", 42);
+        let cloned = m.light_clone();
+        assert_eq!(m, cloned);
+    }
+
+    #[test]
+    fn hash_set_implements_light_clone() {
+        let mut s = im_rc::HashSet::new();
+        s.insert(42);
+        let cloned = s.light_clone();
+        assert_eq!(s, cloned);
+    }
+
+    #[test]
+    fn ord_set_implements_light_clone() {
+        let mut s = im_rc::OrdSet::new();
+        s.insert(42);
+        let cloned = s.light_clone();
+        assert_eq!(s, cloned);
+    }
+}
@@ -4,28 +4,85 @@
 //!
 //! `imbl` is a maintained fork of `im` with improved performance and
 //! better compatibility with newer Rust versions.
+//!
+//! imbl 3.x collections always use `Arc` internally; unlike rpds they have no shared
+//! pointer kind parameter, so every collection here is already thread-safe.
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneDeep, LightCloneSync};
 
-impl<T: Clone> LightClone for imbl::Vector<T> {}
+impl<T: Clone> LightClone for imbl::Vector<T> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
 
 impl<K, V> LightClone for imbl::HashMap<K, V>
 where
     K: Clone + std::hash::Hash + Eq,
     V: Clone,
 {
+    const COST: CloneCost = CloneCost::PERSISTENT;
 }
 
 impl<K, V> LightClone for imbl::OrdMap<K, V>
 where
     K: Clone + Ord,
     V: Clone,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for imbl::HashSet<T>
+where
+    T: Clone + std::hash::Hash + Eq,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T> LightClone for imbl::OrdSet<T>
+where
+    T: Clone + Ord,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: Clone + Send + Sync> LightCloneSync for imbl::Vector<T> {}
+
+impl<K, V> LightCloneSync for imbl::HashMap<K, V>
+where
+    K: Clone + std::hash::Hash + Eq + Send + Sync,
+    V: Clone + Send + Sync,
+{
+}
+
+impl<K, V> LightCloneSync for imbl::OrdMap<K, V>
+where
+    K: Clone + Ord + Send + Sync,
+    V: Clone + Send + Sync,
+{
+}
+
+impl<T> LightCloneSync for imbl::HashSet<T> where T: Clone + std::hash::Hash + Eq + Send + Sync {}
+
+impl<T> LightCloneSync for imbl::OrdSet<T> where T: Clone + Ord + Send + Sync {}
+
+impl<T: LightClone> LightCloneDeep for imbl::Vector<T> {}
+
+impl<K, V> LightCloneDeep for imbl::HashMap<K, V>
+where
+    K: LightClone + std::hash::Hash + Eq,
+    V: LightClone,
+{
+}
+
+impl<K, V> LightCloneDeep for imbl::OrdMap<K, V>
+where
+    K: LightClone + Ord,
+    V: LightClone,
 {
 }
 
-impl<T> LightClone for imbl::HashSet<T> where T: Clone + std::hash::Hash + Eq {}
+impl<T> LightCloneDeep for imbl::HashSet<T> where T: LightClone + std::hash::Hash + Eq {}
 
-impl<T> LightClone for imbl::OrdSet<T> where T: Clone + Ord {}
+impl<T> LightCloneDeep for imbl::OrdSet<T> where T: LightClone + Ord {}
 
 #[cfg(test)]
 mod tests {
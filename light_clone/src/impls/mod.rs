@@ -7,6 +7,9 @@ mod tuples;
 #[cfg(feature = "im")]
 mod im_collections;
 
+#[cfg(feature = "im-rc")]
+mod im_rc_collections;
+
 #[cfg(feature = "imbl")]
 mod imbl_collections;
 
@@ -5,11 +5,23 @@
 //! Both `OrderedFloat<T>` and `NotNan<T>` are `Copy` when `T` is `Copy`,
 //! so cloning is always O(1).
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
-impl<T: Copy> LightClone for ordered_float::OrderedFloat<T> {}
+impl<T: Copy> LightClone for ordered_float::OrderedFloat<T> {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<T: Copy + Send + Sync> LightCloneSync for ordered_float::OrderedFloat<T> {}
+
+impl<T: Copy> LightCopy for ordered_float::OrderedFloat<T> {}
+
+impl<T: Copy> LightClone for ordered_float::NotNan<T> {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<T: Copy + Send + Sync> LightCloneSync for ordered_float::NotNan<T> {}
 
-impl<T: Copy> LightClone for ordered_float::NotNan<T> {}
+impl<T: Copy> LightCopy for ordered_float::NotNan<T> {}
 
 #[cfg(test)]
 mod tests {
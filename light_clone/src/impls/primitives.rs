@@ -1,4 +1,4 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 use std::any::TypeId;
 use std::marker::PhantomPinned;
 use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
@@ -10,11 +10,18 @@ use std::thread::ThreadId;
 use std::time::{Duration, Instant, SystemTime};
 
 /// Macro to implement LightClone for types.
-/// With the marker trait pattern, all impls are empty - the default impl calls `clone()`.
+/// With the marker trait pattern, impls only classify their cost - the default impl
+/// calls `clone()`.
 macro_rules! impl_light_clone {
     ($($t:ty),* $(,)?) => {
         $(
-            impl LightClone for $t {}
+            impl LightClone for $t {
+                const COST: CloneCost = CloneCost::COPY;
+            }
+
+            impl LightCloneSync for $t {}
+
+            impl LightCopy for $t {}
         )*
     };
 }
@@ -68,8 +75,23 @@ impl_light_clone!(
 );
 
 // Shared references are Copy types
-impl<T: ?Sized> LightClone for &T {}
+impl<T: ?Sized> LightClone for &T {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<T: ?Sized + Sync> LightCloneSync for &T {}
+
+impl<T: ?Sized> LightCopy for &T {}
+
+// Raw pointers are Copy types, but neither Send nor Sync
+impl<T: ?Sized> LightClone for *const T {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl<T: ?Sized> LightCopy for *const T {}
+
+impl<T: ?Sized> LightClone for *mut T {
+    const COST: CloneCost = CloneCost::COPY;
+}
 
-// Raw pointers are Copy types
-impl<T: ?Sized> LightClone for *const T {}
-impl<T: ?Sized> LightClone for *mut T {}
+impl<T: ?Sized> LightCopy for *mut T {}
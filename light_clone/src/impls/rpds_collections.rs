@@ -2,37 +2,153 @@
 //!
 //! These implementations are behind the `rpds` feature flag.
 //!
-//! Note: rpds data structures are parameterized by a shared pointer kind (Arc or Rc).
-//! The default type aliases (e.g., `Vector<T>`) use Arc, which is thread-safe.
-//! We implement LightClone for the sync (Arc-based) variants.
+//! rpds data structures are parameterized by a shared pointer kind from `archery`.
+//! The default type aliases (e.g., `Vector<T>`) use `RcK`, and the `*Sync` aliases
+//! (e.g., `VectorSync<T>`) use `ArcTK`, which is thread-safe. The implementations are
+//! generic over `SharedPointerKind`, so both flavours are covered.
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneDeep, LightCloneSync};
+use archery::SharedPointerKind;
+use std::hash::{BuildHasher, Hash};
 
-impl<T: Clone> LightClone for rpds::Vector<T> {}
+impl<T: Clone, P: SharedPointerKind> LightClone for rpds::Vector<T, P> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
 
-impl<K, V> LightClone for rpds::HashTrieMap<K, V>
+impl<K, V, P, H> LightClone for rpds::HashTrieMap<K, V, P, H>
 where
-    K: Clone + std::hash::Hash + Eq,
+    K: Clone + Hash + Eq,
     V: Clone,
+    P: SharedPointerKind,
+    H: BuildHasher + Clone,
 {
+    const COST: CloneCost = CloneCost::PERSISTENT;
 }
 
-impl<K, V> LightClone for rpds::RedBlackTreeMap<K, V>
+impl<K, V, P> LightClone for rpds::RedBlackTreeMap<K, V, P>
 where
     K: Clone + Ord,
     V: Clone,
+    P: SharedPointerKind,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T, P, H> LightClone for rpds::HashTrieSet<T, P, H>
+where
+    T: Clone + Hash + Eq,
+    P: SharedPointerKind,
+    H: BuildHasher + Clone,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T, P> LightClone for rpds::RedBlackTreeSet<T, P>
+where
+    T: Clone + Ord,
+    P: SharedPointerKind,
+{
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: Clone, P: SharedPointerKind> LightClone for rpds::List<T, P> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: Clone, P: SharedPointerKind> LightClone for rpds::Queue<T, P> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+impl<T: Clone, P: SharedPointerKind> LightClone for rpds::Stack<T, P> {
+    const COST: CloneCost = CloneCost::PERSISTENT;
+}
+
+// Only the `Arc`-based pointer kinds (used by the `*Sync` aliases) are thread-safe.
+// They are listed one by one rather than bounded on `P: Send + Sync` so an `RcK`
+// collection finds no impl at all and gets the derive's rpds hint.
+macro_rules! impl_light_clone_sync {
+    ($($kind:ty),*) => {
+        $(
+            impl<T: Clone + Send + Sync> LightCloneSync for rpds::Vector<T, $kind> {}
+
+            impl<K, V, H> LightCloneSync for rpds::HashTrieMap<K, V, $kind, H>
+            where
+                K: Clone + Hash + Eq + Send + Sync,
+                V: Clone + Send + Sync,
+                H: BuildHasher + Clone + Send + Sync,
+            {
+            }
+
+            impl<K, V> LightCloneSync for rpds::RedBlackTreeMap<K, V, $kind>
+            where
+                K: Clone + Ord + Send + Sync,
+                V: Clone + Send + Sync,
+            {
+            }
+
+            impl<T, H> LightCloneSync for rpds::HashTrieSet<T, $kind, H>
+            where
+                T: Clone + Hash + Eq + Send + Sync,
+                H: BuildHasher + Clone + Send + Sync,
+            {
+            }
+
+            impl<T> LightCloneSync for rpds::RedBlackTreeSet<T, $kind>
+            where
+                T: Clone + Ord + Send + Sync,
+            {
+            }
+
+            impl<T: Clone + Send + Sync> LightCloneSync for rpds::List<T, $kind> {}
+
+            impl<T: Clone + Send + Sync> LightCloneSync for rpds::Queue<T, $kind> {}
+
+            impl<T: Clone + Send + Sync> LightCloneSync for rpds::Stack<T, $kind> {}
+        )*
+    };
+}
+
+impl_light_clone_sync!(archery::ArcTK, archery::ArcK);
+
+impl<T: LightClone, P: SharedPointerKind> LightCloneDeep for rpds::Vector<T, P> {}
+
+impl<K, V, P, H> LightCloneDeep for rpds::HashTrieMap<K, V, P, H>
+where
+    K: LightClone + Hash + Eq,
+    V: LightClone,
+    P: SharedPointerKind,
+    H: BuildHasher + Clone,
+{
+}
+
+impl<K, V, P> LightCloneDeep for rpds::RedBlackTreeMap<K, V, P>
+where
+    K: LightClone + Ord,
+    V: LightClone,
+    P: SharedPointerKind,
 {
 }
 
-impl<T> LightClone for rpds::HashTrieSet<T> where T: Clone + std::hash::Hash + Eq {}
+impl<T, P, H> LightCloneDeep for rpds::HashTrieSet<T, P, H>
+where
+    T: LightClone + Hash + Eq,
+    P: SharedPointerKind,
+    H: BuildHasher + Clone,
+{
+}
 
-impl<T> LightClone for rpds::RedBlackTreeSet<T> where T: Clone + Ord {}
+impl<T, P> LightCloneDeep for rpds::RedBlackTreeSet<T, P>
+where
+    T: LightClone + Ord,
+    P: SharedPointerKind,
+{
+}
 
-impl<T: Clone> LightClone for rpds::List<T> {}
+impl<T: LightClone, P: SharedPointerKind> LightCloneDeep for rpds::List<T, P> {}
 
-impl<T: Clone> LightClone for rpds::Queue<T> {}
+impl<T: LightClone, P: SharedPointerKind> LightCloneDeep for rpds::Queue<T, P> {}
 
-impl<T: Clone> LightClone for rpds::Stack<T> {}
+impl<T: LightClone, P: SharedPointerKind> LightCloneDeep for rpds::Stack<T, P> {}
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +209,51 @@ mod tests {
         let cloned = s.light_clone();
         assert_eq!(s, cloned);
     }
+
+    #[test]
+    fn sync_vector_implements_light_clone() {
+        let v: rpds::VectorSync<i32> = rpds::VectorSync::new_sync().push_back(1).push_back(2);
+        let cloned = v.light_clone();
+        assert_eq!(v, cloned);
+    }
+
+    #[test]
+    fn sync_hash_trie_map_implements_light_clone() {
+        let m: rpds::HashTrieMapSync<&str, i32> =
+            rpds::HashTrieMapSync::new_sync().insert("key", 42);
+        let cloned = m.light_clone();
+        assert_eq!(m, cloned);
+    }
+
+    #[test]
+    fn sync_red_black_tree_map_implements_light_clone() {
+        let m: rpds::RedBlackTreeMapSync<&str, i32> =
+            rpds::RedBlackTreeMapSync::new_sync().insert("key", 42);
+        let cloned = m.light_clone();
+        assert_eq!(m, cloned);
+    }
+
+    #[test]
+    fn sync_hash_trie_set_implements_light_clone() {
+        let s: rpds::HashTrieSetSync<i32> = rpds::HashTrieSetSync::new_sync().insert(42);
+        let cloned = s.light_clone();
+        assert_eq!(s, cloned);
+    }
+
+    #[test]
+    fn sync_red_black_tree_set_implements_light_clone() {
+        let s: rpds::RedBlackTreeSetSync<i32> = rpds::RedBlackTreeSetSync::new_sync().insert(42);
+        let cloned = s.light_clone();
+        assert_eq!(s, cloned);
+    }
+
+    #[test]
+    fn sync_list_queue_stack_implement_light_clone() {
+        let l: rpds::ListSync<i32> = rpds::ListSync::new_sync().push_front(1);
+        let q: rpds::QueueSync<i32> = rpds::QueueSync::new_sync().enqueue(1);
+        let s: rpds::StackSync<i32> = rpds::StackSync::new_sync().push(1);
+        assert_eq!(l, l.light_clone());
+        assert_eq!(q, q.light_clone());
+        assert_eq!(s, s.light_clone());
+    }
 }
@@ -5,9 +5,15 @@
 //! `Decimal` is a 128-bit decimal type that implements `Copy`,
 //! so cloning is always O(1).
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
-impl LightClone for rust_decimal::Decimal {}
+impl LightClone for rust_decimal::Decimal {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl LightCloneSync for rust_decimal::Decimal {}
+
+impl LightCopy for rust_decimal::Decimal {}
 
 #[cfg(test)]
 mod tests {
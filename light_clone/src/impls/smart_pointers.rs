@@ -1,9 +1,21 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync};
 
-impl<T: ?Sized> LightClone for std::sync::Arc<T> {}
+impl<T: ?Sized> LightClone for std::sync::Arc<T> {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
 
-impl<T: ?Sized> LightClone for std::rc::Rc<T> {}
+impl<T: ?Sized + Send + Sync> LightCloneSync for std::sync::Arc<T> {}
 
-impl<T: ?Sized> LightClone for std::sync::Weak<T> {}
+impl<T: ?Sized> LightClone for std::rc::Rc<T> {
+    const COST: CloneCost = CloneCost::NON_ATOMIC;
+}
 
-impl<T: ?Sized> LightClone for std::rc::Weak<T> {}
+impl<T: ?Sized> LightClone for std::sync::Weak<T> {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl<T: ?Sized + Send + Sync> LightCloneSync for std::sync::Weak<T> {}
+
+impl<T: ?Sized> LightClone for std::rc::Weak<T> {
+    const COST: CloneCost = CloneCost::NON_ATOMIC;
+}
@@ -7,9 +7,13 @@
 //! bitwise copy for inline strings or an atomic refcount increment for
 //! heap-allocated strings.
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync};
 
-impl LightClone for smol_str::SmolStr {}
+impl LightClone for smol_str::SmolStr {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl LightCloneSync for smol_str::SmolStr {}
 
 #[cfg(test)]
 mod tests {
@@ -4,13 +4,19 @@
 //!
 //! All time crate date/time types are `Copy`, so cloning is always O(1).
 
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
 /// Macro to implement LightClone for types.
 macro_rules! impl_light_clone {
     ($($t:ty),* $(,)?) => {
         $(
-            impl LightClone for $t {}
+            impl LightClone for $t {
+                const COST: CloneCost = CloneCost::COPY;
+            }
+
+            impl LightCloneSync for $t {}
+
+            impl LightCopy for $t {}
         )*
     };
 }
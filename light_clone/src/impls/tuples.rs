@@ -1,8 +1,14 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
 macro_rules! impl_light_clone_for_tuple {
     ($($name:ident),+) => {
-        impl<$($name: LightClone),+> LightClone for ($($name,)+) {}
+        impl<$($name: LightClone),+> LightClone for ($($name,)+) {
+            const COST: CloneCost = CloneCost::COPY$(.plus($name::COST))+;
+        }
+
+        impl<$($name: LightCloneSync),+> LightCloneSync for ($($name,)+) {}
+
+        impl<$($name: LightCopy),+> LightCopy for ($($name,)+) {}
     };
 }
 
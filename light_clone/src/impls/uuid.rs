@@ -1,3 +1,9 @@
-use crate::LightClone;
+use crate::{CloneCost, LightClone, LightCloneSync, LightCopy};
 
-impl LightClone for uuid::Uuid {}
+impl LightClone for uuid::Uuid {
+    const COST: CloneCost = CloneCost::COPY;
+}
+
+impl LightCloneSync for uuid::Uuid {}
+
+impl LightCopy for uuid::Uuid {}
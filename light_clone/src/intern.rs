@@ -0,0 +1,223 @@
+//! String interning: deduplicated [`LightStr`] values that share one allocation.
+//!
+//! Data with many repeated strings (tenant names, metric keys, enum-like labels)
+//! otherwise pays for an allocation per occurrence. An [`Interner`] returns the same
+//! `Arc<str>` for equal strings, and interned strings compare by pointer before falling
+//! back to comparing bytes.
+//!
+//! Entries are held as `Weak<str>`, so a string is reclaimed once the last `LightStr`
+//! using it is dropped. Dead entries are swept as the pool grows, or explicitly with
+//! [`Interner::purge`].
+//!
+//! ```
+//! use light_clone::intern::{intern, Interner};
+//! use light_clone::LightStr;
+//!
+//! // The global pool
+//! let a = intern("tenant-42");
+//! let b = intern(&String::from("tenant-42"));
+//! assert!(LightStr::ptr_eq(&a, &b));
+//!
+//! // A dedicated pool with its own stats
+//! let pool = Interner::new();
+//! let keys: Vec<LightStr> = ["cpu", "mem", "cpu"].iter().map(|k| pool.intern(k)).collect();
+//! assert!(LightStr::ptr_eq(&keys[0], &keys[2]));
+//! assert_eq!(pool.stats().hits, 1);
+//! ```
+
+use crate::LightStr;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, Weak};
+
+/// Number of independently locked shards, so concurrent callers rarely contend.
+const SHARDS: usize = 16;
+
+/// Interns `s` in the global pool.
+///
+/// Equivalent to `Interner::global().intern(s)`.
+pub fn intern(s: &str) -> LightStr {
+    Interner::global().intern(s)
+}
+
+/// A concurrent string interning pool.
+///
+/// Use [`Interner::global`] (or the [`intern`] function) to share one pool across a
+/// program, or create separate pools whose strings and stats are tracked independently.
+pub struct Interner {
+    hasher: RandomState,
+    shards: [Mutex<Shard>; SHARDS],
+    hits: AtomicU64,
+    misses: AtomicU64,
+    bytes_saved: AtomicU64,
+}
+
+/// A snapshot of an [`Interner`]'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// Lookups that returned an existing string.
+    pub hits: u64,
+    /// Lookups that allocated a new string.
+    pub misses: u64,
+    /// Strings still referenced by at least one `LightStr`.
+    pub live_entries: usize,
+    /// Bytes of string data that hits didn't have to allocate.
+    pub bytes_saved: u64,
+}
+
+#[derive(Default)]
+struct Shard {
+    // Keyed by the string's hash, so the pool doesn't keep a second copy of each key
+    entries: HashMap<u64, Vec<Weak<str>>>,
+    len: usize,
+    len_after_sweep: usize,
+}
+
+impl Interner {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Interner {
+            hasher: RandomState::new(),
+            shards: std::array::from_fn(|_| Mutex::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            bytes_saved: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the global pool used by [`intern`].
+    pub fn global() -> &'static Interner {
+        static GLOBAL: OnceLock<Interner> = OnceLock::new();
+        GLOBAL.get_or_init(Interner::new)
+    }
+
+    /// Returns a `LightStr` equal to `s` that shares storage with every other live
+    /// string interned in this pool with the same contents.
+    pub fn intern(&self, s: &str) -> LightStr {
+        let hash = self.hasher.hash_one(s);
+        let mut shard = self.shard(hash);
+        if let Some(existing) = shard.find(hash, s) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            self.bytes_saved
+                .fetch_add(s.len() as u64, Ordering::Relaxed);
+            return LightStr::from(existing);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let interned: Arc<str> = Arc::from(s);
+        shard.insert(hash, Arc::downgrade(&interned));
+        LightStr::from(interned)
+    }
+
+    /// Returns the interned string equal to `s`, if one is alive, without inserting.
+    ///
+    /// Lookups through `get` don't count towards [`InternStats`].
+    pub fn get(&self, s: &str) -> Option<LightStr> {
+        let hash = self.hasher.hash_one(s);
+        self.shard(hash).find(hash, s).map(LightStr::from)
+    }
+
+    /// Drops the entries of strings that are no longer referenced.
+    ///
+    /// This happens automatically as the pool grows. Call it after releasing many
+    /// interned strings at once to free their memory immediately.
+    pub fn purge(&self) {
+        for shard in &self.shards {
+            lock(shard).sweep();
+        }
+    }
+
+    /// Returns the pool's current counters.
+    pub fn stats(&self) -> InternStats {
+        InternStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            live_entries: self.shards.iter().map(|s| lock(s).live()).sum(),
+            bytes_saved: self.bytes_saved.load(Ordering::Relaxed),
+        }
+    }
+
+    fn shard(&self, hash: u64) -> MutexGuard<'_, Shard> {
+        lock(&self.shards[hash as usize % SHARDS])
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        Interner::new()
+    }
+}
+
+impl std::fmt::Debug for Interner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interner")
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+// A panic while holding the lock can't leave a shard inconsistent, so poisoning is ignored
+fn lock(shard: &Mutex<Shard>) -> MutexGuard<'_, Shard> {
+    shard.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Shard {
+    fn find(&self, hash: u64, s: &str) -> Option<Arc<str>> {
+        self.entries
+            .get(&hash)?
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|candidate| **candidate == *s)
+    }
+
+    fn insert(&mut self, hash: u64, entry: Weak<str>) {
+        let bucket = self.entries.entry(hash).or_default();
+        let before = bucket.len();
+        bucket.retain(|weak| weak.strong_count() > 0);
+        let removed = before - bucket.len();
+        bucket.push(entry);
+        self.len = self.len - removed + 1;
+
+        // Sweeping whenever the shard doubles keeps the cost amortized O(1) per insert
+        if self.len >= 2 * self.len_after_sweep.max(SHARDS) {
+            self.sweep();
+        }
+    }
+
+    fn sweep(&mut self) {
+        self.entries.retain(|_, bucket| {
+            bucket.retain(|weak| weak.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.len = self.entries.values().map(Vec::len).sum();
+        self.len_after_sweep = self.len;
+    }
+
+    fn live(&self) -> usize {
+        self.entries
+            .values()
+            .flatten()
+            .filter(|weak| weak.strong_count() > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_counts_dead_entries_it_drops() {
+        let mut shard = Shard::default();
+        for _ in 0..100 {
+            let s: Arc<str> = Arc::from("x");
+            shard.insert(7, Arc::downgrade(&s));
+        }
+
+        // Each insert replaced the previous dead entry, so no sweep was ever needed
+        assert_eq!(shard.len, 1);
+        assert_eq!(shard.len_after_sweep, 0);
+    }
+}
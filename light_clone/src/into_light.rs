@@ -0,0 +1,545 @@
+//! The [`IntoLight`] trait: one conversion entry point from heavy std types to their
+//! light counterparts.
+//!
+//! Element types are converted recursively, so `Vec<Option<String>>` becomes
+//! `Arc<[Option<LightStr>]>`. Maps and sets become persistent collections from the first
+//! enabled collection feature, in order `im`, `imbl`, `rpds` (the thread-safe `*Sync`
+//! aliases), and otherwise an `Arc` around the std collection.
+
+use crate::{
+    IntoLightOsStr, IntoLightPath, IntoLightStr, LightClone, LightOsStr, LightPath, LightSlice,
+    LightStr, LightSubstr,
+};
+use std::array;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::OsString;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Converts a value into its [`LightClone`] equivalent.
+///
+/// Meant for boundary layers (deserialization, config loading, API handlers) that
+/// receive std types and hand light ones to the rest of the program:
+///
+/// | Heavy type | Light type |
+/// |---|---|
+/// | `String`, `&str`, `Box<str>`, `Cow<str>` | [`LightStr`] |
+/// | `PathBuf` | [`LightPath`] |
+/// | `OsString` | [`LightOsStr`] |
+/// | `Vec<T>`, `VecDeque<T>`, `Box<[T]>` | `Arc<[T::Light]>` |
+/// | `Box<T>` | `Arc<T::Light>` |
+/// | `[T; N]` | `Arc<[T::Light; N]>` |
+/// | `HashMap<K, V>`, `BTreeMap<K, V>` | persistent map of `K::Light` to `V::Light` |
+/// | `HashSet<T>`, `BTreeSet<T>` | persistent set of `T::Light` |
+/// | `Option<T>`, `Result<T, E>`, tuples | the same shape with light elements |
+///
+/// Primitives and types that are already light (`Arc`, `Rc`, `LightStr`, `LightSlice`,
+/// ...) convert to themselves.
+///
+/// Maps and sets become `im::HashMap`/`im::OrdMap`/`im::HashSet`/`im::OrdSet` with the
+/// `im` feature, the `imbl` equivalents with `imbl`, the rpds `*Sync` collections with
+/// `rpds`, and `Arc<HashMap<..>>` (etc.) with none of them. Since Cargo unifies
+/// features, another crate enabling one of these features changes the target types, so
+/// prefer inferring `Light` over naming the collection type.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLight, LightClone, LightStr};
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+///
+/// let tags: Vec<String> = vec!["new".into(), "sale".into()];
+/// let light_tags: Arc<[LightStr]> = tags.into_light();
+/// assert_eq!(light_tags[1], "sale");
+///
+/// let prices = HashMap::from([(String::from("book"), Some(12u32))]);
+/// let light_prices = prices.into_light();
+/// assert_eq!(light_prices.get("book"), Some(&Some(12)));
+/// let _copy = light_prices.light_clone(); // O(1)
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no known light equivalent",
+    label = "`{Self}` does not implement `IntoLight`",
+    note = "derive `LightMirror` on `{Self}` to generate a light twin, or implement `IntoLight` for it",
+    note = "in a `LightMirror` type, use `#[light_mirror(keep)]` for fields that are already `LightClone`, or `#[light_mirror(into = \"...\")]` to pick the light type"
+)]
+pub trait IntoLight {
+    /// The light equivalent of `Self`.
+    type Light: LightClone;
+
+    /// Converts this value into its light equivalent.
+    #[must_use]
+    fn into_light(self) -> Self::Light;
+}
+
+/// Converts a light value back into its heavy std equivalent, the reverse of
+/// [`IntoLight`].
+///
+/// Takes the light value by reference, since light values are usually shared, and
+/// copies the data out into freshly allocated std types.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{FromLight, IntoLight};
+///
+/// let names = vec![String::from("ann"), String::from("bo")];
+/// let light = names.clone().into_light();
+/// assert_eq!(Vec::<String>::from_light(&light), names);
+/// ```
+pub trait FromLight: IntoLight + Sized {
+    /// Creates the heavy value from its light equivalent.
+    #[must_use]
+    fn from_light(light: &Self::Light) -> Self;
+}
+
+/// Map and set targets for the `im` feature.
+#[cfg(feature = "im")]
+mod targets {
+    pub(super) type HashMap<K, V> = im::HashMap<K, V>;
+    pub(super) type OrdMap<K, V> = im::OrdMap<K, V>;
+    pub(super) type HashSet<T> = im::HashSet<T>;
+    pub(super) type OrdSet<T> = im::OrdSet<T>;
+
+    pub(super) fn collect<T, C: FromIterator<T>>(iter: impl Iterator<Item = T>) -> C {
+        iter.collect()
+    }
+}
+
+/// Map and set targets for the `imbl` feature.
+#[cfg(all(feature = "imbl", not(feature = "im")))]
+mod targets {
+    pub(super) type HashMap<K, V> = imbl::HashMap<K, V>;
+    pub(super) type OrdMap<K, V> = imbl::OrdMap<K, V>;
+    pub(super) type HashSet<T> = imbl::HashSet<T>;
+    pub(super) type OrdSet<T> = imbl::OrdSet<T>;
+
+    pub(super) fn collect<T, C: FromIterator<T>>(iter: impl Iterator<Item = T>) -> C {
+        iter.collect()
+    }
+}
+
+/// Map and set targets for the `rpds` feature, using the thread-safe aliases.
+#[cfg(all(feature = "rpds", not(any(feature = "im", feature = "imbl"))))]
+mod targets {
+    pub(super) type HashMap<K, V> = rpds::HashTrieMapSync<K, V>;
+    pub(super) type OrdMap<K, V> = rpds::RedBlackTreeMapSync<K, V>;
+    pub(super) type HashSet<T> = rpds::HashTrieSetSync<T>;
+    pub(super) type OrdSet<T> = rpds::RedBlackTreeSetSync<T>;
+
+    pub(super) fn collect<T, C: FromIterator<T>>(iter: impl Iterator<Item = T>) -> C {
+        iter.collect()
+    }
+}
+
+/// Map and set targets without a collection feature: the std collection behind an `Arc`.
+#[cfg(not(any(feature = "im", feature = "imbl", feature = "rpds")))]
+mod targets {
+    use std::sync::Arc;
+
+    pub(super) type HashMap<K, V> = Arc<std::collections::HashMap<K, V>>;
+    pub(super) type OrdMap<K, V> = Arc<std::collections::BTreeMap<K, V>>;
+    pub(super) type HashSet<T> = Arc<std::collections::HashSet<T>>;
+    pub(super) type OrdSet<T> = Arc<std::collections::BTreeSet<T>>;
+
+    pub(super) fn collect<T, C: FromIterator<T>>(iter: impl Iterator<Item = T>) -> Arc<C> {
+        Arc::new(iter.collect())
+    }
+}
+
+/// Implements `IntoLight` and `FromLight` as the identity for types that are already
+/// light.
+macro_rules! impl_into_light_identity {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl IntoLight for $t {
+                type Light = $t;
+
+                fn into_light(self) -> $t {
+                    self
+                }
+            }
+
+            impl FromLight for $t {
+                fn from_light(light: &$t) -> $t {
+                    light.light_clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_light_identity!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    bool,
+    char,
+    (),
+    Duration,
+    LightStr,
+    LightSubstr,
+);
+
+impl<T: ?Sized> IntoLight for Arc<T> {
+    type Light = Arc<T>;
+
+    fn into_light(self) -> Arc<T> {
+        self
+    }
+}
+
+impl<T: ?Sized> FromLight for Arc<T> {
+    fn from_light(light: &Arc<T>) -> Arc<T> {
+        Arc::clone(light)
+    }
+}
+
+impl<T: ?Sized> IntoLight for Rc<T> {
+    type Light = Rc<T>;
+
+    fn into_light(self) -> Rc<T> {
+        self
+    }
+}
+
+impl<T: ?Sized> FromLight for Rc<T> {
+    fn from_light(light: &Rc<T>) -> Rc<T> {
+        Rc::clone(light)
+    }
+}
+
+impl<T> IntoLight for LightSlice<T> {
+    type Light = LightSlice<T>;
+
+    fn into_light(self) -> LightSlice<T> {
+        self
+    }
+}
+
+impl<T> FromLight for LightSlice<T> {
+    fn from_light(light: &LightSlice<T>) -> LightSlice<T> {
+        light.light_clone()
+    }
+}
+
+/// Implements `IntoLight` for string types by delegating to `IntoLightStr`.
+macro_rules! impl_into_light_str {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl IntoLight for $t {
+                type Light = LightStr;
+
+                fn into_light(self) -> LightStr {
+                    self.into_light_str()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_light_str!(String, &str, &String, Box<str>, Cow<'_, str>);
+
+impl FromLight for String {
+    fn from_light(light: &LightStr) -> String {
+        String::from(light.as_str())
+    }
+}
+
+impl FromLight for Box<str> {
+    fn from_light(light: &LightStr) -> Box<str> {
+        Box::from(light.as_str())
+    }
+}
+
+impl FromLight for Cow<'_, str> {
+    fn from_light(light: &LightStr) -> Self {
+        Cow::Owned(String::from(light.as_str()))
+    }
+}
+
+impl IntoLight for PathBuf {
+    type Light = LightPath;
+
+    fn into_light(self) -> LightPath {
+        self.into_light_path()
+    }
+}
+
+impl FromLight for PathBuf {
+    fn from_light(light: &LightPath) -> PathBuf {
+        light.to_path_buf()
+    }
+}
+
+impl IntoLight for OsString {
+    type Light = LightOsStr;
+
+    fn into_light(self) -> LightOsStr {
+        self.into_light_os_str()
+    }
+}
+
+impl FromLight for OsString {
+    fn from_light(light: &LightOsStr) -> OsString {
+        light.to_os_string()
+    }
+}
+
+impl<T: IntoLight> IntoLight for Vec<T> {
+    type Light = Arc<[T::Light]>;
+
+    fn into_light(self) -> Self::Light {
+        self.into_iter().map(IntoLight::into_light).collect()
+    }
+}
+
+impl<T: FromLight> FromLight for Vec<T> {
+    fn from_light(light: &Self::Light) -> Self {
+        light.iter().map(T::from_light).collect()
+    }
+}
+
+impl<T: IntoLight> IntoLight for VecDeque<T> {
+    type Light = Arc<[T::Light]>;
+
+    fn into_light(self) -> Self::Light {
+        self.into_iter().map(IntoLight::into_light).collect()
+    }
+}
+
+impl<T: FromLight> FromLight for VecDeque<T> {
+    fn from_light(light: &Self::Light) -> Self {
+        light.iter().map(T::from_light).collect()
+    }
+}
+
+impl<T: IntoLight> IntoLight for Box<[T]> {
+    type Light = Arc<[T::Light]>;
+
+    fn into_light(self) -> Self::Light {
+        self.into_vec().into_light()
+    }
+}
+
+impl<T: FromLight> FromLight for Box<[T]> {
+    fn from_light(light: &Self::Light) -> Self {
+        light.iter().map(T::from_light).collect()
+    }
+}
+
+impl<T: IntoLight> IntoLight for Box<T> {
+    type Light = Arc<T::Light>;
+
+    fn into_light(self) -> Self::Light {
+        Arc::new((*self).into_light())
+    }
+}
+
+impl<T: FromLight> FromLight for Box<T> {
+    fn from_light(light: &Self::Light) -> Self {
+        Box::new(T::from_light(light))
+    }
+}
+
+impl<T: IntoLight> IntoLight for Option<T> {
+    type Light = Option<T::Light>;
+
+    fn into_light(self) -> Self::Light {
+        self.map(IntoLight::into_light)
+    }
+}
+
+impl<T: FromLight> FromLight for Option<T> {
+    fn from_light(light: &Self::Light) -> Self {
+        light.as_ref().map(T::from_light)
+    }
+}
+
+impl<T: IntoLight, E: IntoLight> IntoLight for Result<T, E> {
+    type Light = Result<T::Light, E::Light>;
+
+    fn into_light(self) -> Self::Light {
+        self.map(IntoLight::into_light)
+            .map_err(IntoLight::into_light)
+    }
+}
+
+impl<T: FromLight, E: FromLight> FromLight for Result<T, E> {
+    fn from_light(light: &Self::Light) -> Self {
+        match light {
+            Ok(value) => Ok(T::from_light(value)),
+            Err(error) => Err(E::from_light(error)),
+        }
+    }
+}
+
+// Arrays are only `LightClone` when `Copy`, so they are shared behind an `Arc`
+impl<T: IntoLight, const N: usize> IntoLight for [T; N] {
+    type Light = Arc<[T::Light; N]>;
+
+    fn into_light(self) -> Self::Light {
+        Arc::new(self.map(IntoLight::into_light))
+    }
+}
+
+impl<T: FromLight, const N: usize> FromLight for [T; N] {
+    fn from_light(light: &Self::Light) -> Self {
+        array::from_fn(|i| T::from_light(&light[i]))
+    }
+}
+
+macro_rules! impl_into_light_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IntoLight),+> IntoLight for ($($name,)+) {
+            type Light = ($($name::Light,)+);
+
+            #[allow(non_snake_case)]
+            fn into_light(self) -> Self::Light {
+                let ($($name,)+) = self;
+                ($($name.into_light(),)+)
+            }
+        }
+
+        impl<$($name: FromLight),+> FromLight for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn from_light(light: &Self::Light) -> Self {
+                let ($($name,)+) = light;
+                ($($name::from_light($name),)+)
+            }
+        }
+    };
+}
+
+impl_into_light_for_tuple!(A);
+impl_into_light_for_tuple!(A, B);
+impl_into_light_for_tuple!(A, B, C);
+impl_into_light_for_tuple!(A, B, C, D);
+impl_into_light_for_tuple!(A, B, C, D, E);
+impl_into_light_for_tuple!(A, B, C, D, E, F);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G, H);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_into_light_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl<K, V> IntoLight for HashMap<K, V>
+where
+    K: IntoLight,
+    K::Light: Hash + Eq,
+    V: IntoLight,
+{
+    type Light = targets::HashMap<K::Light, V::Light>;
+
+    fn into_light(self) -> Self::Light {
+        targets::collect(
+            self.into_iter()
+                .map(|(k, v)| (k.into_light(), v.into_light())),
+        )
+    }
+}
+
+impl<K, V> FromLight for HashMap<K, V>
+where
+    K: FromLight + Hash + Eq,
+    K::Light: Hash + Eq,
+    V: FromLight,
+{
+    fn from_light(light: &Self::Light) -> Self {
+        light
+            .iter()
+            .map(|(k, v)| (K::from_light(k), V::from_light(v)))
+            .collect()
+    }
+}
+
+impl<K, V> IntoLight for BTreeMap<K, V>
+where
+    K: IntoLight,
+    K::Light: Ord,
+    V: IntoLight,
+{
+    type Light = targets::OrdMap<K::Light, V::Light>;
+
+    fn into_light(self) -> Self::Light {
+        targets::collect(
+            self.into_iter()
+                .map(|(k, v)| (k.into_light(), v.into_light())),
+        )
+    }
+}
+
+impl<K, V> FromLight for BTreeMap<K, V>
+where
+    K: FromLight + Ord,
+    K::Light: Ord,
+    V: FromLight,
+{
+    fn from_light(light: &Self::Light) -> Self {
+        light
+            .iter()
+            .map(|(k, v)| (K::from_light(k), V::from_light(v)))
+            .collect()
+    }
+}
+
+impl<T> IntoLight for HashSet<T>
+where
+    T: IntoLight,
+    T::Light: Hash + Eq,
+{
+    type Light = targets::HashSet<T::Light>;
+
+    fn into_light(self) -> Self::Light {
+        targets::collect(self.into_iter().map(IntoLight::into_light))
+    }
+}
+
+impl<T> FromLight for HashSet<T>
+where
+    T: FromLight + Hash + Eq,
+    T::Light: Hash + Eq,
+{
+    fn from_light(light: &Self::Light) -> Self {
+        light.iter().map(T::from_light).collect()
+    }
+}
+
+impl<T> IntoLight for BTreeSet<T>
+where
+    T: IntoLight,
+    T::Light: Ord,
+{
+    type Light = targets::OrdSet<T::Light>;
+
+    fn into_light(self) -> Self::Light {
+        targets::collect(self.into_iter().map(IntoLight::into_light))
+    }
+}
+
+impl<T> FromLight for BTreeSet<T>
+where
+    T: FromLight + Ord,
+    T::Light: Ord,
+{
+    fn from_light(light: &Self::Light) -> Self {
+        light.iter().map(T::from_light).collect()
+    }
+}
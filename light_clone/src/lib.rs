@@ -1,16 +1,45 @@
+// Lets generated code refer to `::light_clone` from inside this crate
+extern crate self as light_clone;
+
 mod aliases;
 mod conversions;
+mod cost;
+mod diagnostics;
 mod impls;
+pub mod intern;
+mod into_light;
+mod light_slice;
+mod light_str;
+mod light_substr;
+mod macros;
 mod trait_def;
 
 // Re-export the trait
-pub use trait_def::LightClone;
+pub use trait_def::{LightClone, LightCloneDeep, LightCloneSync, LightCopy};
+
+// Re-export clone cost classification
+pub use cost::{CloneCost, CostTier, MAX_COPY_SIZE};
 
-// Re-export the derive macro
-pub use light_clone_derive::LightClone;
+// Re-export the derive and attribute macros
+pub use light_clone_derive::{
+    enforce, no_heavy_clone, LightClone, LightCloneSync, LightCopy, LightMirror,
+};
+
+// Re-export the string and slice types
+pub use light_slice::LightSlice;
+pub use light_str::LightStr;
+pub use light_substr::{LightStrArena, LightSubstr};
 
 // Re-export type aliases
-pub use aliases::LightStr;
+pub use aliases::{LightBytes, LightOsStr, LightPath, LightPathExt};
 
 // Re-export conversion traits
-pub use conversions::IntoLightStr;
+pub use conversions::{IntoLightOsStr, IntoLightPath, IntoLightSlice, IntoLightStr, ToLightStr};
+pub use into_light::{FromLight, IntoLight};
+
+// Not public API: used by code generated from the derive, attribute and expression macros
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::diagnostics::*;
+    pub use crate::macros::{CopyFallback, Probe, ViaCopy, ViaLightClone};
+}
@@ -0,0 +1,275 @@
+use crate::{CloneCost, LightClone, LightCloneSync};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Bound, Deref, Range, RangeBounds};
+use std::sync::Arc;
+
+/// An immutable, cheaply-cloneable slice: an `Arc<[T]>` plus a range into it.
+///
+/// Cloning copies a pointer and bumps a reference count, and [`slice`](Self::slice)
+/// returns a sub-slice that shares the same allocation, so splitting a large buffer into
+/// records never copies the elements. A `LightSlice` dereferences to `[T]` and compares
+/// and hashes like `[T]`.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{LightClone, LightSlice};
+///
+/// // Collecting from an exact-size iterator allocates once
+/// let readings: LightSlice<u32> = (1..=6).collect();
+///
+/// let first_half = readings.slice(..3);
+/// let second_half = readings.slice(3..);
+/// assert_eq!(first_half, [1, 2, 3]);
+/// assert_eq!(second_half.iter().sum::<u32>(), 15);
+///
+/// let copy = readings.light_clone(); // O(1)
+/// assert!(LightSlice::ptr_eq(&copy, &readings));
+/// ```
+pub struct LightSlice<T> {
+    buf: Arc<[T]>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> LightSlice<T> {
+    /// Returns the elements as a `&[T]`.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buf[self.start..self.end]
+    }
+
+    /// Returns a view of the given range that shares this slice's allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like indexing a slice.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = resolve_range(range, self.len());
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range {}..{} is out of bounds for a slice of length {}",
+            range.start,
+            range.end,
+            self.len()
+        );
+        LightSlice {
+            buf: Arc::clone(&self.buf),
+            start: self.start + range.start,
+            end: self.start + range.end,
+        }
+    }
+
+    /// Returns `true` if both slices view the same range of the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.buf, &other.buf) && this.start == other.start && this.end == other.end
+    }
+}
+
+/// Converts `range` to a `start..end` range, using `len` for an unbounded end.
+///
+/// The result is not checked against `len`.
+pub(crate) fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    start..end
+}
+
+impl<T> LightClone for LightSlice<T> {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl<T: Send + Sync> LightCloneSync for LightSlice<T> {}
+
+// Manual impl: cloning shares the allocation, so it doesn't need `T: Clone`
+impl<T> Clone for LightSlice<T> {
+    fn clone(&self) -> Self {
+        LightSlice {
+            buf: Arc::clone(&self.buf),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<T> Default for LightSlice<T> {
+    fn default() -> Self {
+        LightSlice::from(Arc::from([]))
+    }
+}
+
+impl<T> Deref for LightSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for LightSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> Borrow<[T]> for LightSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LightSlice<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LightSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for LightSlice<T> {}
+
+impl<T: PartialEq> PartialEq<[T]> for LightSlice<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for LightSlice<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for LightSlice<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for LightSlice<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for LightSlice<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord> Ord for LightSlice<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+// Must match `[T]`'s `Hash` for `Borrow<[T]>` lookups
+impl<T: Hash> Hash for LightSlice<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LightSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T> FromIterator<T> for LightSlice<T> {
+    /// Collects into a single allocation when the iterator reports an exact size (as
+    /// every `ExactSizeIterator` does), and through a `Vec` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator reports an exact size but yields a different number of
+    /// items, which is a bug in the iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        if upper != Some(lower) {
+            return LightSlice::from(iter.collect::<Vec<T>>());
+        }
+
+        // `Take<RepeatWith>` has a trusted length, so `Arc<[T]>` allocates exactly once
+        let exact: Arc<[T]> = std::iter::repeat_with(|| {
+            iter.next()
+                .expect("iterator yielded fewer items than its exact size hint")
+        })
+        .take(lower)
+        .collect();
+        assert!(
+            iter.next().is_none(),
+            "iterator yielded more items than its exact size hint"
+        );
+        LightSlice::from(exact)
+    }
+}
+
+impl<T> From<Arc<[T]>> for LightSlice<T> {
+    /// Views the whole `Arc`, without copying.
+    fn from(buf: Arc<[T]>) -> Self {
+        let end = buf.len();
+        LightSlice { buf, start: 0, end }
+    }
+}
+
+impl<T> From<Vec<T>> for LightSlice<T> {
+    fn from(vec: Vec<T>) -> Self {
+        LightSlice::from(Arc::from(vec))
+    }
+}
+
+impl<T> From<Box<[T]>> for LightSlice<T> {
+    fn from(boxed: Box<[T]>) -> Self {
+        LightSlice::from(Arc::from(boxed))
+    }
+}
+
+impl<T: Clone> From<&[T]> for LightSlice<T> {
+    fn from(slice: &[T]) -> Self {
+        LightSlice::from(Arc::from(slice))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LightSlice<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<LightSlice<T>> for Arc<[T]> {
+    /// Reuses the `Arc` when the slice views all of it, and copies the elements
+    /// otherwise.
+    fn from(slice: LightSlice<T>) -> Self {
+        if slice.start == 0 && slice.end == slice.buf.len() {
+            slice.buf
+        } else {
+            Arc::from(slice.as_slice())
+        }
+    }
+}
+
+impl<T: Clone> From<LightSlice<T>> for Vec<T> {
+    fn from(slice: LightSlice<T>) -> Self {
+        slice.as_slice().to_vec()
+    }
+}
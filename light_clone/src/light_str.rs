@@ -0,0 +1,397 @@
+use crate::{CloneCost, LightClone, LightCloneSync};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Longest string stored inline, chosen so `LightStr` is no larger than `String`.
+const INLINE_CAP: usize = 22;
+
+/// An immutable, cheaply-cloneable string.
+///
+/// Cloning a `LightStr` never copies heap data. Depending on how it was created, a
+/// `LightStr` holds:
+///
+/// - a `&'static str`, via the `const fn` [`LightStr::from_static`] (no allocation),
+/// - up to 22 bytes inline (no allocation, cloned by copying 24 bytes),
+/// - an `Arc<str>` for anything longer (cloned with one atomic increment).
+///
+/// It dereferences to `str`, and compares and hashes exactly like `str`, so it works as
+/// a `HashMap` key looked up by `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLightStr, LightClone, LightStr};
+///
+/// const GREETING: LightStr = LightStr::from_static("hello");
+///
+/// // Short strings are stored inline without allocating
+/// let name: LightStr = "Alice".into_light_str();
+///
+/// // Clone is O(1): a copy or a refcount bump, never a string copy
+/// let name_copy = name.light_clone();
+/// assert_eq!(name, name_copy);
+/// assert_eq!(GREETING, "hello");
+/// assert!(name.starts_with('A'));
+/// ```
+///
+/// # Interop with `Arc<str>`
+///
+/// Converting from an `Arc<str>` keeps sharing the same allocation, and converting back
+/// reuses it when there is one:
+///
+/// ```
+/// use light_clone::LightStr;
+/// use std::sync::Arc;
+///
+/// let shared: Arc<str> = Arc::from("a string that is too long to be stored inline");
+/// let light = LightStr::from(shared.clone());
+/// let back: Arc<str> = light.into();
+/// assert!(Arc::ptr_eq(&shared, &back));
+/// ```
+#[derive(Clone)]
+pub struct LightStr(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    Inline { len: u8, buf: [u8; INLINE_CAP] },
+    Heap(Arc<str>),
+}
+
+impl LightStr {
+    /// Creates a `LightStr` from a string literal or other `'static` string without
+    /// allocating.
+    pub const fn from_static(s: &'static str) -> Self {
+        LightStr(Repr::Static(s))
+    }
+
+    /// Creates a `LightStr` by copying `s`, inline if it is short enough and into a new
+    /// `Arc<str>` otherwise.
+    pub fn new(s: &str) -> Self {
+        Self::inline(s).unwrap_or_else(|| LightStr(Repr::Heap(Arc::from(s))))
+    }
+
+    /// Formats `args` into a `LightStr`, allocating at most once.
+    ///
+    /// Usually called through [`light_format!`](crate::light_format). Format strings
+    /// without arguments are stored as `'static`, and results of up to 22 bytes inline.
+    /// Longer results are measured first and then written straight into an exactly-sized
+    /// `Arc<str>`, so the `Display` impls involved run twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Display` impl returns an error, like `format!`.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        if let Some(s) = args.as_str() {
+            return LightStr::from_static(s);
+        }
+
+        let mut buf = [0; INLINE_CAP];
+        let len = write_fmt_into(&mut buf, args);
+        if len <= INLINE_CAP {
+            return LightStr(Repr::Inline {
+                len: len as u8,
+                buf,
+            });
+        }
+
+        // `Take<Repeat>` has a trusted length, so this collects into one allocation
+        let mut bytes: Arc<[u8]> = std::iter::repeat(0).take(len).collect();
+        let dst = Arc::get_mut(&mut bytes).expect("freshly collected `Arc` is unique");
+        if write_fmt_into(dst, args) != len {
+            // The output changed between passes: fall back to the two-allocation path
+            return LightStr::from(fmt::format(args));
+        }
+        // SAFETY: `write_fmt_into` filled all `len` bytes with whole `&str` pieces, so
+        // they are valid UTF-8, and `str` has the same layout as `[u8]`
+        LightStr(Repr::Heap(unsafe {
+            Arc::from_raw(Arc::into_raw(bytes) as *const str)
+        }))
+    }
+
+    fn inline(s: &str) -> Option<Self> {
+        if s.len() > INLINE_CAP {
+            return None;
+        }
+        let mut buf = [0; INLINE_CAP];
+        buf[..s.len()].copy_from_slice(s.as_bytes());
+        Some(LightStr(Repr::Inline {
+            len: s.len() as u8,
+            buf,
+        }))
+    }
+
+    /// Returns the string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(s) => s,
+            // SAFETY: `buf[..len]` was copied from a `&str` in `LightStr::inline`
+            Repr::Inline { len, buf } => unsafe {
+                std::str::from_utf8_unchecked(&buf[..usize::from(*len)])
+            },
+            Repr::Heap(s) => s,
+        }
+    }
+
+    /// Returns the string if it was created from a `&'static str`.
+    pub(crate) fn as_static(&self) -> Option<&'static str> {
+        match self.0 {
+            Repr::Static(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if both strings share the same heap allocation or `'static` string.
+    ///
+    /// Inline strings are copied on clone, so they never share storage.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        match (&this.0, &other.0) {
+            (Repr::Static(a), Repr::Static(b)) => std::ptr::eq(*a, *b),
+            (Repr::Heap(a), Repr::Heap(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Formats `args` into `buf` and returns the full output length.
+///
+/// Each piece is copied only if it fits entirely, so the written prefix is valid UTF-8
+/// whenever the returned length is at most `buf.len()`.
+fn write_fmt_into(buf: &mut [u8], args: fmt::Arguments<'_>) -> usize {
+    struct SliceWriter<'a> {
+        buf: &'a mut [u8],
+        len: usize,
+    }
+
+    impl fmt::Write for SliceWriter<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if let Some(dst) = self.buf.get_mut(self.len..end) {
+                dst.copy_from_slice(s.as_bytes());
+            }
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut writer = SliceWriter { buf, len: 0 };
+    fmt::write(&mut writer, args).expect("a Display implementation returned an error unexpectedly");
+    writer.len
+}
+
+impl LightClone for LightStr {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl LightCloneSync for LightStr {}
+
+impl Default for LightStr {
+    fn default() -> Self {
+        LightStr::from_static("")
+    }
+}
+
+impl Deref for LightStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for LightStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for LightStr {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Borrow<str> for LightStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for LightStr {
+    fn eq(&self, other: &Self) -> bool {
+        // Interned and cloned strings share storage, so skip comparing their bytes
+        LightStr::ptr_eq(self, other) || self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for LightStr {}
+
+impl PartialEq<str> for LightStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LightStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for LightStr {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<LightStr> for str {
+    fn eq(&self, other: &LightStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<LightStr> for &str {
+    fn eq(&self, other: &LightStr) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<LightStr> for String {
+    fn eq(&self, other: &LightStr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialOrd for LightStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LightStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+// Must match `str`'s `Hash` for `Borrow<str>` lookups
+impl Hash for LightStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for LightStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for LightStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl FromStr for LightStr {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(LightStr::new(s))
+    }
+}
+
+impl From<&str> for LightStr {
+    fn from(s: &str) -> Self {
+        LightStr::new(s)
+    }
+}
+
+impl From<&String> for LightStr {
+    fn from(s: &String) -> Self {
+        LightStr::new(s)
+    }
+}
+
+impl From<String> for LightStr {
+    fn from(s: String) -> Self {
+        LightStr::inline(&s).unwrap_or_else(|| LightStr(Repr::Heap(Arc::from(s))))
+    }
+}
+
+impl From<Arc<str>> for LightStr {
+    /// Wraps the `Arc` as is, so the allocation stays shared even for short strings.
+    fn from(s: Arc<str>) -> Self {
+        LightStr(Repr::Heap(s))
+    }
+}
+
+impl From<LightStr> for Arc<str> {
+    /// Reuses the `Arc` if the string has one, and allocates otherwise.
+    fn from(s: LightStr) -> Self {
+        match s.0 {
+            Repr::Heap(s) => s,
+            _ => Arc::from(s.as_str()),
+        }
+    }
+}
+
+impl From<LightStr> for String {
+    fn from(s: LightStr) -> Self {
+        s.as_str().to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_no_larger_than_string() {
+        assert!(std::mem::size_of::<LightStr>() <= std::mem::size_of::<String>());
+    }
+
+    #[test]
+    fn picks_storage_by_length() {
+        assert!(matches!(LightStr::from_static("static").0, Repr::Static(_)));
+        assert!(matches!(LightStr::new("").0, Repr::Inline { len: 0, .. }));
+        assert!(matches!(
+            LightStr::new(&"x".repeat(INLINE_CAP)).0,
+            Repr::Inline { .. }
+        ));
+        assert!(matches!(
+            LightStr::from("x".repeat(INLINE_CAP + 1)).0,
+            Repr::Heap(_)
+        ));
+        assert!(matches!(LightStr::default().0, Repr::Static("")));
+    }
+
+    #[test]
+    fn from_fmt_picks_storage_by_length() {
+        let n = 7;
+        assert!(matches!(
+            LightStr::from_fmt(format_args!("literal")).0,
+            Repr::Static("literal")
+        ));
+        assert!(matches!(
+            LightStr::from_fmt(format_args!("n = {n}")).0,
+            Repr::Inline { len: 5, .. }
+        ));
+        let long = LightStr::from_fmt(format_args!("{n} is a number long enough for the heap"));
+        assert!(matches!(long.0, Repr::Heap(_)));
+        assert_eq!(long, "7 is a number long enough for the heap");
+    }
+
+    #[test]
+    fn inline_keeps_multibyte_characters() {
+        let s = LightStr::new("héllo wörld ✓");
+        assert!(matches!(s.0, Repr::Inline { .. }));
+        assert_eq!(s.as_str(), "héllo wörld ✓");
+    }
+}
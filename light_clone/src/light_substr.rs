@@ -0,0 +1,355 @@
+use crate::light_slice;
+use crate::{CloneCost, IntoLightStr, LightClone, LightCloneSync, LightStr};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Range, RangeBounds};
+
+/// A zero-copy view into part of a [`LightStr`].
+///
+/// A `LightSubstr` keeps its parent alive and stores a byte range into it, so slicing a
+/// large input never copies text: every token of a parsed document can share the
+/// document's single `Arc<str>`. Like `LightStr`, it dereferences to `str` and compares
+/// and hashes like `str`.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::{IntoLightStr, LightStr, LightSubstr};
+///
+/// let request = LightStr::from("GET /index.html HTTP/1.1");
+///
+/// let method = request.substr(..3);
+/// let words: Vec<LightSubstr> = request
+///     .split(' ')
+///     .map(|word| request.substr_from(word))
+///     .collect();
+///
+/// assert_eq!(method, "GET");
+/// assert_eq!(words[1], "/index.html");
+///
+/// // Promote to a standalone string once the parent should be released
+/// let path: LightStr = words[1].clone().into_light_str();
+/// assert_eq!(path, "/index.html");
+/// ```
+///
+/// A substring keeps the whole parent allocation alive. Promote long-lived substrings
+/// that are much smaller than their parent with [`IntoLightStr`].
+#[derive(Clone)]
+pub struct LightSubstr {
+    parent: LightStr,
+    start: usize,
+    end: usize,
+}
+
+impl LightStr {
+    /// Returns a view of the given byte range that shares this string's storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or doesn't fall on `char` boundaries, like
+    /// indexing a `str`.
+    pub fn substr(&self, range: impl RangeBounds<usize>) -> LightSubstr {
+        LightSubstr::from_parts(self.light_clone(), resolve_range(range, self))
+    }
+
+    /// Returns a view of `substr`, which must be a slice of this string (e.g. from
+    /// `split` or `trim`), sharing this string's storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `substr` doesn't point into this string; see
+    /// [`try_substr_from`](Self::try_substr_from) for a non-panicking version.
+    pub fn substr_from(&self, substr: &str) -> LightSubstr {
+        self.try_substr_from(substr)
+            .expect("`substr_from` called with a string that is not a slice of this `LightStr`")
+    }
+
+    /// Returns a view of `substr` if it is a slice of this string, and `None` otherwise.
+    pub fn try_substr_from(&self, substr: &str) -> Option<LightSubstr> {
+        let start = (substr.as_ptr() as usize).checked_sub(self.as_ptr() as usize)?;
+        let end = start + substr.len();
+        self.get(start..end)?;
+        Some(LightSubstr::from_parts(self.light_clone(), start..end))
+    }
+}
+
+impl LightSubstr {
+    fn from_parts(parent: LightStr, range: Range<usize>) -> Self {
+        // A `'static` parent can be sliced directly, which makes promotion free
+        if let Some(parent) = parent.as_static() {
+            let text = &parent[range];
+            return LightSubstr {
+                parent: LightStr::from_static(text),
+                start: 0,
+                end: text.len(),
+            };
+        }
+        LightSubstr {
+            parent,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the substring as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.parent[self.start..self.end]
+    }
+
+    /// Returns the string this is a view into.
+    pub fn parent(&self) -> &LightStr {
+        &self.parent
+    }
+
+    /// Returns the byte range of this view within [`parent`](Self::parent).
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns a view of a byte range of this substring, sharing the same parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or doesn't fall on `char` boundaries.
+    pub fn substr(&self, range: impl RangeBounds<usize>) -> LightSubstr {
+        let range = resolve_range(range, self);
+        LightSubstr::from_parts(
+            self.parent.light_clone(),
+            self.start + range.start..self.start + range.end,
+        )
+    }
+}
+
+/// Converts `range` to a byte range of `text`, panicking like `str` indexing if it is out
+/// of bounds or splits a `char`.
+fn resolve_range(range: impl RangeBounds<usize>, text: &str) -> Range<usize> {
+    let range = light_slice::resolve_range(range, text.len());
+    assert!(
+        text.get(range.clone()).is_some(),
+        "byte range {}..{} is out of bounds or not on a char boundary of a string of length {}",
+        range.start,
+        range.end,
+        text.len()
+    );
+    range
+}
+
+impl LightClone for LightSubstr {
+    const COST: CloneCost = CloneCost::ATOMIC;
+}
+
+impl LightCloneSync for LightSubstr {}
+
+impl From<LightStr> for LightSubstr {
+    /// Views the whole string.
+    fn from(parent: LightStr) -> Self {
+        let end = parent.len();
+        LightSubstr {
+            parent,
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl From<LightSubstr> for LightStr {
+    fn from(substr: LightSubstr) -> Self {
+        substr.into_light_str()
+    }
+}
+
+impl IntoLightStr for LightSubstr {
+    /// Promotes the view to a standalone [`LightStr`], releasing the parent.
+    ///
+    /// Returns the parent itself when the view covers all of it, and copies the text
+    /// otherwise (inline if it is short enough).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use light_clone::{IntoLightStr, LightStr};
+    ///
+    /// let text = LightStr::from("key=value");
+    /// let key = text.substr(..3).into_light_str();
+    /// assert_eq!(key, "key");
+    /// ```
+    fn into_light_str(self) -> LightStr {
+        if self.start == 0 && self.end == self.parent.len() {
+            self.parent
+        } else {
+            LightStr::new(self.as_str())
+        }
+    }
+}
+
+impl Deref for LightSubstr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for LightSubstr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for LightSubstr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for LightSubstr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for LightSubstr {}
+
+impl PartialEq<str> for LightSubstr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LightSubstr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<LightStr> for LightSubstr {
+    fn eq(&self, other: &LightStr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<LightSubstr> for LightStr {
+    fn eq(&self, other: &LightSubstr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<LightSubstr> for &str {
+    fn eq(&self, other: &LightSubstr) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialOrd for LightSubstr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LightSubstr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+// Must match `str`'s `Hash` for `Borrow<str>` lookups
+impl Hash for LightSubstr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for LightSubstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for LightSubstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Packs many small strings into one shared buffer.
+///
+/// Building thousands of short strings one by one costs an allocation each. An arena
+/// copies them into a single buffer instead, and [`finish`](Self::finish) turns the
+/// buffer into one [`LightStr`] and returns a [`LightSubstr`] per pushed string.
+///
+/// # Examples
+///
+/// ```
+/// use light_clone::LightStrArena;
+///
+/// let mut arena = LightStrArena::new();
+/// let first = arena.push("alpha");
+/// let second = arena.push("beta");
+///
+/// let strings = arena.finish();
+/// assert_eq!(strings[first], "alpha");
+/// assert_eq!(strings[second], "beta");
+/// assert_eq!(strings[first].parent(), "alphabeta");
+/// ```
+///
+/// Every handle keeps the whole buffer alive, so an arena suits strings with similar
+/// lifetimes, such as the names in one parsed file.
+#[derive(Debug, Default)]
+pub struct LightStrArena {
+    buf: String,
+    ranges: Vec<Range<usize>>,
+}
+
+impl LightStrArena {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty arena with room for `bytes` bytes of text.
+    pub fn with_capacity(bytes: usize) -> Self {
+        LightStrArena {
+            buf: String::with_capacity(bytes),
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Copies `s` into the arena and returns its index in the [`finish`](Self::finish)
+    /// output.
+    pub fn push(&mut self, s: &str) -> usize {
+        let start = self.buf.len();
+        self.buf.push_str(s);
+        self.ranges.push(start..self.buf.len());
+        self.ranges.len() - 1
+    }
+
+    /// Returns the number of strings pushed so far.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if no strings have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Moves the buffer into a single [`LightStr`] and returns a view of each pushed
+    /// string, in push order.
+    pub fn finish(self) -> Vec<LightSubstr> {
+        let parent = LightStr::from(self.buf);
+        self.ranges
+            .into_iter()
+            .map(|range| LightSubstr::from_parts(parent.light_clone(), range))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for LightStrArena {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s.as_ref());
+        }
+    }
+}
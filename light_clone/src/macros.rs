@@ -0,0 +1,217 @@
+//! Expression macros for explicit cheap-clone call sites.
+//!
+//! `lc_move!` light-clones the variables a `move` closure or `async move` block
+//! captures, so the originals stay usable after the closure is built.
+//!
+//! `light_format!` is `format!` for [`LightStr`](crate::LightStr).
+//!
+//! `light_clone!` picks an implementation with autoref specialization: the macro calls
+//! `__light_clone` on `&&Probe(&value)`. Method lookup tries the receiver as written
+//! first, which only matches [`ViaLightClone`] (implemented for `&Probe<T>` when
+//! `T: LightClone`), then auto-derefs once to [`ViaCopy`] (implemented for every
+//! `Probe<T>`, with a `Copy` requirement on the method). Since the choice is made on
+//! the concrete type at the call site, it works without impls for third-party types.
+
+/// Wraps the value being cloned so the macro can pick an implementation.
+#[doc(hidden)]
+pub struct Probe<'a, T>(pub &'a T);
+
+/// Preferred implementation: the type implements `LightClone`.
+#[doc(hidden)]
+pub trait ViaLightClone {
+    type Output;
+
+    fn __light_clone(&self) -> Self::Output;
+}
+
+impl<T: crate::LightClone> ViaLightClone for &Probe<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn __light_clone(&self) -> T {
+        self.0.light_clone()
+    }
+}
+
+/// Fallback implementation: the type is `Copy` but has no `LightClone` impl.
+#[doc(hidden)]
+pub trait ViaCopy {
+    type Output;
+
+    fn __light_clone(&self) -> Self::Output
+    where
+        Self::Output: CopyFallback;
+}
+
+impl<T> ViaCopy for Probe<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn __light_clone(&self) -> T
+    where
+        T: CopyFallback,
+    {
+        *self.0
+    }
+}
+
+/// Reports values that are neither `LightClone` nor `Copy`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is neither `LightClone` nor `Copy`",
+    label = "`light_clone!` can't clone this cheaply",
+    note = "`light_clone!` accepts `LightClone` types and any `Copy` type",
+    note = "call `.clone()` explicitly if a deep copy is intended"
+)]
+pub trait CopyFallback: Copy {}
+
+impl<T: Copy> CopyFallback for T {}
+
+/// Light-clones a value, falling back to a bitwise copy for `Copy` types without a
+/// [`LightClone`](crate::LightClone) impl.
+///
+/// Third-party `Copy` types (bitflags, generated protobuf enums, math vectors) can't
+/// get a `LightClone` impl outside their crate, so `.lc()` isn't available on them.
+/// `light_clone!` gives them the same explicit cheap-clone call site:
+///
+/// ```
+/// use light_clone::light_clone;
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Vec3 { x: f32, y: f32, z: f32 } // no `LightClone` impl
+///
+/// let position = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let name: Arc<str> = Arc::from("player");
+///
+/// assert_eq!(light_clone!(position), position); // plain copy
+/// assert!(Arc::ptr_eq(&light_clone!(name), &name)); // `LightClone::light_clone`
+/// ```
+///
+/// Anything else fails to compile, so the macro can't hide a deep copy:
+///
+/// ```compile_fail
+/// use light_clone::light_clone;
+///
+/// let name = String::from("player");
+/// let copy = light_clone!(name);
+/// ```
+///
+/// The macro takes the value by reference. The implementation is chosen from the type
+/// at the call site, so inside generic code `T` needs a `LightClone` or `Copy` bound.
+#[macro_export]
+macro_rules! light_clone {
+    ($value:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{ViaCopy as _, ViaLightClone as _};
+        (&&$crate::__private::Probe(&$value)).__light_clone()
+    }};
+}
+
+/// Shorthand for [`light_clone!`].
+///
+/// ```
+/// use light_clone::lc;
+/// use std::sync::Arc;
+///
+/// let config: Arc<str> = Arc::from("config");
+/// let handle = lc!(config);
+/// assert!(Arc::ptr_eq(&handle, &config));
+/// ```
+#[macro_export]
+macro_rules! lc {
+    ($value:expr $(,)?) => {
+        $crate::light_clone!($value)
+    };
+}
+
+/// Light-clones a list of captures into fresh bindings, then evaluates a closure or
+/// `async` block that moves them.
+///
+/// Feeding a `move` closure or a spawned future usually takes a `let x = x.lc();` line
+/// per capture. `lc_move!` writes them for you:
+///
+/// ```
+/// use light_clone::lc_move;
+/// use std::sync::Arc;
+///
+/// struct Server {
+///     config: Arc<str>,
+/// }
+///
+/// let server = Server { config: Arc::from("prod") };
+/// let name: Arc<str> = Arc::from("worker");
+///
+/// let worker = lc_move!([name, cfg = server.config] move || format!("{name}@{cfg}"));
+/// let handle = std::thread::spawn(worker);
+///
+/// assert_eq!(handle.join().unwrap(), "worker@prod");
+/// assert_eq!(&*name, "worker"); // still usable here
+/// ```
+///
+/// Each capture is one of:
+///
+/// - `name`, which light-clones `name` into a binding of the same name,
+/// - `a.b.c`, a field path, which binds the last segment (`c`),
+/// - `alias = expr`, which light-clones any place expression into `alias`.
+///
+/// The body can be any expression, typically a `move` closure or an `async move`
+/// block. Every capture goes through [`LightClone::light_clone`](crate::LightClone),
+/// so a capture that would deep-copy (e.g. a `String`) fails to compile.
+#[macro_export]
+macro_rules! lc_move {
+    ([$($captures:tt)*] $body:expr $(,)?) => {{
+        $crate::__lc_move_captures!($($captures)*);
+        $body
+    }};
+}
+
+/// Expands the capture list of [`lc_move!`] into `let` bindings, one capture at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lc_move_captures {
+    () => {};
+    // A field path binds its last segment; the path is accumulated segment by segment
+    (@path [$($path:tt)*] $segment:ident . $($rest:tt)*) => {
+        $crate::__lc_move_captures!(@path [$($path)* . $segment] $($rest)*);
+    };
+    (@path [$($path:tt)*] $last:ident $(, $($rest:tt)*)?) => {
+        let $last = $crate::LightClone::light_clone(&$($path)* . $last);
+        $($crate::__lc_move_captures!($($rest)*);)?
+    };
+    ($alias:ident = $value:expr $(, $($rest:tt)*)?) => {
+        let $alias = $crate::LightClone::light_clone(&$value);
+        $($crate::__lc_move_captures!($($rest)*);)?
+    };
+    ($root:ident . $($rest:tt)*) => {
+        $crate::__lc_move_captures!(@path [$root] $($rest)*);
+    };
+    ($name:ident $(, $($rest:tt)*)?) => {
+        let $name = $crate::LightClone::light_clone(&$name);
+        $($crate::__lc_move_captures!($($rest)*);)?
+    };
+}
+
+/// Creates a [`LightStr`](crate::LightStr) using interpolation of runtime expressions.
+///
+/// Takes the same arguments as `format!`, but skips the intermediate `String`: a format
+/// string without arguments becomes a `'static` `LightStr`, results of up to 22 bytes are
+/// stored inline, and longer results are written into a single `Arc<str>` allocation.
+///
+/// ```
+/// use light_clone::{light_format, LightStr};
+///
+/// let user = "alice";
+/// let id = 42;
+///
+/// let key: LightStr = light_format!("{user}:{id}");
+/// assert_eq!(key, "alice:42");
+/// ```
+///
+/// See [`LightStr::from_fmt`](crate::LightStr::from_fmt) for the details.
+#[macro_export]
+macro_rules! light_format {
+    ($($arg:tt)*) => {
+        $crate::LightStr::from_fmt(::core::format_args!($($arg)*))
+    };
+}
@@ -1,3 +1,5 @@
+use crate::CloneCost;
+
 /// Marker trait for types that are O(1) to clone.
 ///
 /// `LightClone` is a marker trait that asserts a type's `Clone` implementation is cheap.
@@ -51,8 +53,37 @@
 /// ```
 ///
 /// The compile-time enforcement comes from the generated bounds - if any field doesn't
-/// implement `LightClone`, compilation will fail.
+/// implement `LightClone`, compilation will fail. Each field is checked separately, so
+/// the error points at the offending field and, for common heavy types like `String`
+/// or `Vec<T>`, suggests a light replacement.
+///
+/// # Clone cost
+///
+/// Every implementation classifies its clone with the [`COST`](LightClone::COST)
+/// associated const, so code can check how light a type is. The derive combines the
+/// costs of the fields: the most expensive tier plus the total refcount increments.
+///
+/// ```
+/// use light_clone::{CloneCost, LightClone};
+/// use std::rc::Rc;
+/// use std::sync::Arc;
+///
+/// assert_eq!(u64::COST, CloneCost::COPY);
+/// assert_eq!(<Rc<str>>::COST, CloneCost::NON_ATOMIC);
+/// assert_eq!(<(Arc<str>, Arc<str>)>::COST.refcounts(), 2);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `LightClone`",
+    label = "`{Self}` is not known to be O(1) to clone",
+    note = "`LightClone` is implemented for `Copy` types, `Arc`/`Rc`, and persistent collections",
+    note = "replace `String` with `LightStr`, `Vec<T>` with `Arc<[T]>`, `HashMap<K, V>` with `im::HashMap<K, V>`, and `Box<T>` with `Arc<T>`"
+)]
 pub trait LightClone: Clone {
+    /// How expensive a light clone of this type is.
+    ///
+    /// Manual implementations should set this; it defaults to [`CloneCost::UNKNOWN`].
+    const COST: CloneCost = CloneCost::UNKNOWN;
+
     /// Returns a light clone of the value.
     ///
     /// This operation is guaranteed to be O(1), involving only:
@@ -63,6 +94,8 @@ pub trait LightClone: Clone {
     /// `LightClone` types since their `Clone` is guaranteed to be O(1).
     #[inline]
     fn light_clone(&self) -> Self {
+        // Evaluating the cost rejects oversized bitwise copies at compile time
+        let _cost = Self::COST;
         self.clone()
     }
 
@@ -72,3 +105,111 @@ pub trait LightClone: Clone {
         self.light_clone()
     }
 }
+
+/// Marker trait for light clones that are safe to share across threads.
+///
+/// `LightClone` accepts both `Rc` and `Arc`, so a type deriving it can hold an `Rc` and
+/// silently stop being `Send`. `LightCloneSync` is implemented only for the `Copy` and
+/// atomically refcounted types, so it rules out `Rc`, `rc::Weak`, `Cell`, `im_rc`
+/// collections and the `Rc`-based rpds collections.
+///
+/// # Derive Macro
+///
+/// `#[derive(LightCloneSync)]` requires every field to be `LightCloneSync`, next to
+/// `#[derive(Clone, LightClone)]`:
+///
+/// ```
+/// use light_clone::{LightClone, LightCloneSync};
+/// use std::sync::Arc;
+///
+/// #[derive(Clone, LightClone, LightCloneSync)]
+/// struct Snapshot {
+///     version: u64,
+///     body: Arc<str>,
+/// }
+///
+/// fn share<T: LightCloneSync + 'static>(value: &T) {
+///     let value = value.light_clone();
+///     std::thread::spawn(move || drop(value)).join().unwrap();
+/// }
+///
+/// share(&Snapshot { version: 1, body: Arc::from("hello") });
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `LightCloneSync`",
+    label = "`{Self}` is not a thread-safe light clone",
+    note = "`LightCloneSync` is implemented for `Copy` types, `Arc`, and thread-safe persistent collections",
+    note = "replace `Rc<T>` with `Arc<T>`, `im_rc` collections with `im`, and rpds collections with their `*Sync` aliases"
+)]
+pub trait LightCloneSync: LightClone + Send + Sync {}
+
+/// Marker trait for light clones that are a pure bitwise copy.
+///
+/// `LightClone` covers both `Copy` types and refcounted pointers. `LightCopy` is the
+/// subset whose clone does no refcount traffic at all, for lock-free hot paths where
+/// even an atomic increment is too much. It is implemented for the built-in `Copy`
+/// impls: primitives, references, raw and function pointers, `NonNull`, tuples, arrays,
+/// `Option`, `Result`, `Bound` and `Poll` of `LightCopy` types, and the `Copy` types of
+/// the optional integrations.
+///
+/// # Derive Macro
+///
+/// `#[derive(LightCopy)]` requires every field to be `LightCopy` and also generates the
+/// `LightClone` impl, so it replaces `#[derive(LightClone)]`:
+///
+/// ```
+/// use light_clone::{CloneCost, LightClone, LightCopy};
+///
+/// #[derive(Clone, Copy, LightCopy)]
+/// struct Tick {
+///     price: f64,
+///     size: u32,
+/// }
+///
+/// fn hot_path<T: LightCopy>(value: &T) -> T {
+///     value.light_clone()
+/// }
+///
+/// let tick = hot_path(&Tick { price: 1.5, size: 10 });
+/// assert_eq!(tick.size, 10);
+/// assert_eq!(Tick::COST, CloneCost::COPY);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `LightCopy`",
+    label = "`{Self}` is not a bitwise light clone",
+    note = "`LightCopy` is implemented for `Copy` types; refcounted types such as `Arc` and `Rc` are only `LightClone`"
+)]
+pub trait LightCopy: LightClone + Copy {}
+
+/// Marker trait for persistent collections whose elements are light clones too.
+///
+/// Cloning a persistent collection never clones its elements, but mutating the clone
+/// copies the chunks along the modified path, elements included. `im::Vector<String>`
+/// is `LightClone`, yet a push after a clone deep-copies a chunk of `String`s.
+/// `LightCloneDeep` is implemented for the `im`, `im-rc`, `imbl` and `rpds` collections
+/// only when their keys and values are `LightClone`, so "clone then mutate" stays cheap
+/// end to end:
+///
+/// ```
+/// # #[cfg(feature = "im")]
+/// # {
+/// use light_clone::{LightClone, LightCloneDeep, LightStr};
+///
+/// fn clone_then_push<T: LightCloneDeep>(items: &T, push: impl FnOnce(&mut T)) -> T {
+///     let mut items = items.light_clone();
+///     push(&mut items);
+///     items
+/// }
+///
+/// let tags: im::Vector<LightStr> = im::vector![LightStr::from("new")];
+/// let more = clone_then_push(&tags, |tags| tags.push_back(LightStr::from("sale")));
+/// assert_eq!(more.len(), 2);
+/// # }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `LightCloneDeep`",
+    label = "mutating a clone of `{Self}` may deep-copy its elements",
+    note = "`LightCloneDeep` is implemented for persistent collections whose keys and values are `LightClone`",
+    note = "replace `String` elements with `LightStr` and `Vec<T>` elements with `Arc<[T]>`"
+)]
+pub trait LightCloneDeep: LightClone {}
@@ -0,0 +1,118 @@
+use light_clone::{CloneCost, CostTier, LightClone};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[test]
+fn test_builtin_costs() {
+    assert_eq!(u64::COST, CloneCost::COPY);
+    assert_eq!(<&str>::COST, CloneCost::COPY);
+    assert_eq!(<[u8; 16]>::COST, CloneCost::COPY);
+    assert_eq!(<Arc<str>>::COST, CloneCost::ATOMIC);
+    assert_eq!(<Rc<str>>::COST, CloneCost::NON_ATOMIC);
+    assert_eq!(<Option<Arc<str>>>::COST, CloneCost::ATOMIC);
+    assert_eq!(<PhantomData<String>>::COST, CloneCost::COPY);
+}
+
+#[test]
+fn test_tuple_cost_adds_up() {
+    let cost = <(u8, Rc<str>, Arc<str>, Arc<[u8]>)>::COST;
+    assert_eq!(cost.tier(), CostTier::Atomic);
+    assert_eq!(cost.refcounts(), 3);
+}
+
+#[test]
+fn test_result_cost_is_most_expensive_variant() {
+    assert_eq!(<Result<u8, Arc<str>>>::COST, CloneCost::ATOMIC);
+    assert_eq!(<Result<Rc<str>, u8>>::COST, CloneCost::NON_ATOMIC);
+}
+
+#[derive(Clone, LightClone)]
+#[allow(dead_code)]
+struct Message {
+    id: u64,
+    topic: Arc<str>,
+    payload: Arc<[u8]>,
+}
+
+#[test]
+fn test_derived_struct_cost() {
+    assert_eq!(Message::COST, CloneCost::new(CostTier::Atomic, 2));
+    assert!(Message::COST.fits_within(CloneCost::new(CostTier::Atomic, 2)));
+    assert!(!Message::COST.fits_within(CloneCost::ATOMIC));
+}
+
+#[derive(Clone, Copy, LightClone)]
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn test_derived_copy_struct_cost() {
+    assert_eq!(Point::COST, CloneCost::COPY);
+}
+
+#[derive(Clone, LightClone)]
+#[allow(dead_code)]
+enum Event {
+    Idle,
+    Local(Rc<str>, Rc<str>),
+    Shared(Arc<str>),
+    Moved(Point),
+}
+
+#[test]
+fn test_derived_enum_cost_is_most_expensive_variant() {
+    assert_eq!(Event::COST, CloneCost::ATOMIC);
+}
+
+#[derive(Clone, LightClone)]
+#[allow(dead_code)]
+struct Wrapper<T> {
+    inner: T,
+    shared: Arc<T>,
+}
+
+#[test]
+fn test_derived_generic_cost() {
+    assert_eq!(<Wrapper<u32>>::COST, CloneCost::ATOMIC);
+    assert_eq!(<Wrapper<Arc<str>>>::COST.refcounts(), 2);
+}
+
+#[derive(Clone)]
+struct Manual;
+
+impl LightClone for Manual {}
+
+#[test]
+fn test_manual_impl_defaults_to_unknown() {
+    assert_eq!(Manual::COST, CloneCost::UNKNOWN);
+    assert_eq!(<(Manual, u8)>::COST.tier(), CostTier::Unknown);
+}
+
+#[test]
+fn test_cost_ordering() {
+    assert!(CloneCost::COPY < CloneCost::NON_ATOMIC);
+    assert!(CloneCost::NON_ATOMIC < CloneCost::ATOMIC);
+    assert!(CloneCost::ATOMIC < CloneCost::new(CostTier::Atomic, 2));
+    assert!(CloneCost::new(CostTier::Atomic, 5) < CloneCost::PERSISTENT);
+    assert_eq!(
+        CloneCost::NON_ATOMIC.max(CloneCost::ATOMIC),
+        CloneCost::ATOMIC
+    );
+}
+
+#[test]
+fn test_cost_display() {
+    assert_eq!(CloneCost::COPY.to_string(), "copy");
+    assert_eq!(
+        CloneCost::ATOMIC.to_string(),
+        "atomic refcount (1 increment)"
+    );
+    assert_eq!(Message::COST.to_string(), "atomic refcount (2 increments)");
+}
+
+// Costs are usable in const context, e.g. to guard hot-path types at compile time
+const _: () = assert!(Point::COST.fits_within(CloneCost::COPY));
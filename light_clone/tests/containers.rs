@@ -62,7 +62,7 @@ fn option_none() {
 
 #[test]
 fn result_ok_with_arc_str() {
-    let value: Result<Arc<str>, String> = Ok(Arc::from("success"));
+    let value: Result<Arc<str>, Arc<str>> = Ok(Arc::from("success"));
     let cloned = value.light_clone();
 
     match (&value, &cloned) {
@@ -75,21 +75,20 @@ fn result_ok_with_arc_str() {
 
 #[test]
 fn result_err_case() {
-    let value: Result<Arc<str>, String> = Err("error".to_string());
+    let value: Result<Arc<str>, Arc<str>> = Err(Arc::from("error"));
     let cloned = value.light_clone();
 
     match (&value, &cloned) {
         (Err(original), Err(clone)) => {
-            assert_eq!(original, clone);
+            assert!(Arc::ptr_eq(original, clone));
         }
         _ => panic!("Expected Err variants"),
     }
 }
 
 #[test]
-fn result_with_clone_only_error_type() {
-    // String implements Clone but not LightClone, which is allowed for E
-    let value: Result<i32, String> = Err("error message".to_string());
+fn result_with_copy_error_type() {
+    let value: Result<Arc<str>, u16> = Err(404);
     let cloned = value.light_clone();
     assert_eq!(value, cloned);
 }
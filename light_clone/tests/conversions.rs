@@ -1,4 +1,6 @@
 use light_clone::{IntoLightStr, LightClone, LightStr};
+use std::borrow::Cow;
+use std::rc::Rc;
 use std::sync::Arc;
 
 #[test]
@@ -26,15 +28,15 @@ fn into_light_str_from_string_ref() {
 #[test]
 fn into_light_str_returns_light_str_type() {
     let s: LightStr = "hello".into_light_str();
-    let _arc: Arc<str> = s; // Compiles because LightStr is Arc<str>
+    let _arc: Arc<str> = s.into();
 }
 
 #[test]
 fn into_light_str_result_is_light_clone() {
-    let s = "hello".into_light_str();
+    let s = "a string long enough to be stored on the heap".into_light_str();
     let cloned = s.light_clone();
     assert_eq!(&*s, &*cloned);
-    assert_eq!(Arc::strong_count(&s), 2);
+    assert!(LightStr::ptr_eq(&s, &cloned));
 }
 
 #[test]
@@ -88,11 +90,11 @@ fn multiple_into_light_str_calls() {
 
 #[test]
 fn into_light_str_from_light_str_is_idempotent() {
-    let s: LightStr = Arc::from("hello");
+    let s = LightStr::from_static("hello");
     let s2 = s.clone().into_light_str();
     assert_eq!(&*s2, "hello");
-    // Both refer to the same allocation
-    assert!(Arc::ptr_eq(&s, &s2));
+    // Both refer to the same static string
+    assert!(LightStr::ptr_eq(&s, &s2));
 }
 
 #[test]
@@ -110,8 +112,48 @@ fn into_light_str_works_in_generic_context() {
     assert_eq!(&*s2, "world");
 
     // Works with LightStr
-    let s3: LightStr = Arc::from("existing");
+    let s3 = LightStr::from_static("existing");
     let s4 = takes_into_light_str(s3.clone());
     assert_eq!(&*s4, "existing");
-    assert!(Arc::ptr_eq(&s3, &s4));
+    assert!(LightStr::ptr_eq(&s3, &s4));
+
+    // Works with Arc<str>, keeping the allocation
+    let shared: Arc<str> = Arc::from("shared");
+    let s5 = takes_into_light_str(shared.clone());
+    assert_eq!(&*s5, "shared");
+    assert_eq!(Arc::strong_count(&shared), 2);
+}
+
+#[test]
+fn into_light_str_from_str_ref_ref() {
+    let words = ["alpha", "beta"];
+    let light: Vec<LightStr> = words.iter().map(IntoLightStr::into_light_str).collect();
+    assert_eq!(light, ["alpha", "beta"]);
+}
+
+#[test]
+fn into_light_str_from_cow() {
+    let borrowed: Cow<str> = Cow::Borrowed("borrowed");
+    let owned: Cow<str> = Cow::Owned(String::from("owned"));
+    assert_eq!(borrowed.into_light_str(), "borrowed");
+    assert_eq!(owned.into_light_str(), "owned");
+}
+
+#[test]
+fn into_light_str_from_boxed_and_rc_str() {
+    let boxed: Box<str> = Box::from("a boxed string long enough for the heap");
+    assert_eq!(
+        boxed.into_light_str(),
+        "a boxed string long enough for the heap"
+    );
+
+    let rc: Rc<str> = Rc::from("rc");
+    assert_eq!(rc.clone().into_light_str(), "rc");
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn into_light_str_from_char() {
+    assert_eq!('a'.into_light_str(), "a");
+    assert_eq!('✓'.into_light_str(), "✓");
 }
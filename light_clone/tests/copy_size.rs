@@ -0,0 +1,95 @@
+use light_clone::{CloneCost, CostTier, LightClone, MAX_COPY_SIZE};
+use std::cell::Cell;
+use std::sync::Arc;
+
+#[test]
+fn test_array_within_budget() {
+    let buffer = [7u8; MAX_COPY_SIZE];
+    let copy = buffer.light_clone();
+    assert_eq!(buffer, copy);
+    assert_eq!(<[u8; MAX_COPY_SIZE]>::COST, CloneCost::COPY);
+}
+
+#[test]
+fn test_cell_within_budget() {
+    assert_eq!(<Cell<[u64; 4]>>::COST, CloneCost::COPY);
+}
+
+#[test]
+fn test_manual_impl_can_use_copy_of() {
+    #[derive(Clone, Copy)]
+    struct Rgba([u8; 4]);
+
+    impl LightClone for Rgba {
+        const COST: CloneCost = CloneCost::copy_of::<Self>();
+    }
+
+    assert_eq!(Rgba([1, 2, 3, 4]).light_clone().0[3], 4);
+    assert_eq!(Rgba::COST, CloneCost::COPY);
+}
+
+// Two halves that each fit the budget but not together, allowed by an explicit budget
+#[derive(Clone, Copy, LightClone)]
+#[light_clone(max_size = 1024)]
+struct Snapshot {
+    before: [u8; MAX_COPY_SIZE],
+    after: [u8; MAX_COPY_SIZE],
+}
+
+#[test]
+fn test_max_size_replaces_budget() {
+    let snapshot = Snapshot {
+        before: [0; MAX_COPY_SIZE],
+        after: [1; MAX_COPY_SIZE],
+    };
+    let copy = snapshot.light_clone();
+    assert_eq!(copy.before[0], 0);
+    assert_eq!(copy.after[0], 1);
+    assert_eq!(Snapshot::COST, CloneCost::COPY);
+}
+
+// max_size also bounds types that aren't bitwise copies
+#[derive(Clone, LightClone)]
+#[light_clone(max_size = 24)]
+#[allow(dead_code)]
+struct Message {
+    id: u64,
+    topic: Arc<str>,
+}
+
+#[test]
+fn test_max_size_on_refcounted_type() {
+    assert_eq!(Message::COST.tier(), CostTier::Atomic);
+}
+
+#[derive(Clone, Copy, LightClone)]
+#[light_clone(max_size = 64)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn test_max_size_on_generic_type() {
+    let g = Generic { value: 5u32 };
+    assert_eq!(g.light_clone().value, 5);
+    assert_eq!(<Generic<u32>>::COST, CloneCost::COPY);
+}
+
+// A where clause doesn't defer the size check: `Bounded` is checked where it is
+// declared, like any non-generic type (see `tests/ui/oversized_where_clause.rs`)
+#[derive(Clone, Copy, LightClone)]
+struct Bounded
+where
+    u8: Copy,
+{
+    value: u64,
+}
+
+#[test]
+fn test_where_clause_on_non_generic_type() {
+    let bounded = Bounded { value: 9 };
+    assert_eq!(bounded.light_clone().value, 9);
+    assert_eq!(Bounded::COST, CloneCost::COPY);
+    // The derive only emits this const for types it checks where they are declared
+    assert_eq!(Bounded::__LIGHT_CLONE_COST, CloneCost::COPY);
+}
@@ -0,0 +1,63 @@
+//! Integration tests for `LightCloneDeep` on persistent collections.
+
+#![cfg(any(feature = "im", feature = "im-rc", feature = "imbl", feature = "rpds"))]
+
+use light_clone::{LightClone, LightCloneDeep, LightStr};
+
+fn assert_light_clone_deep<T: LightCloneDeep>() {}
+
+#[test]
+fn collections_of_light_elements_are_light_clone_deep() {
+    #[cfg(feature = "im")]
+    {
+        assert_light_clone_deep::<im::Vector<LightStr>>();
+        assert_light_clone_deep::<im::HashMap<u32, LightStr>>();
+        assert_light_clone_deep::<im::OrdSet<u64>>();
+    }
+    #[cfg(feature = "im-rc")]
+    {
+        assert_light_clone_deep::<im_rc::Vector<LightStr>>();
+        assert_light_clone_deep::<im_rc::OrdMap<LightStr, u32>>();
+    }
+    #[cfg(feature = "imbl")]
+    {
+        assert_light_clone_deep::<imbl::HashMap<LightStr, u64>>();
+        assert_light_clone_deep::<imbl::HashSet<u32>>();
+    }
+    #[cfg(feature = "rpds")]
+    {
+        assert_light_clone_deep::<rpds::HashTrieMap<u32, LightStr>>();
+        assert_light_clone_deep::<rpds::VectorSync<LightStr>>();
+        assert_light_clone_deep::<rpds::List<u8>>();
+    }
+}
+
+// `LightCloneDeep` only adds impls: collections of heavy elements stay `LightClone`
+#[test]
+fn collections_of_heavy_elements_stay_light_clone() {
+    fn assert_light_clone<T: LightClone>() {}
+
+    #[cfg(feature = "im")]
+    assert_light_clone::<im::Vector<String>>();
+    #[cfg(feature = "im-rc")]
+    assert_light_clone::<im_rc::Vector<String>>();
+    #[cfg(feature = "imbl")]
+    assert_light_clone::<imbl::HashMap<u32, Vec<u8>>>();
+    #[cfg(feature = "rpds")]
+    assert_light_clone::<rpds::HashTrieMap<String, u32>>();
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn mutating_a_clone_shares_elements() {
+    assert_light_clone_deep::<im::Vector<LightStr>>();
+
+    let original: im::Vector<LightStr> = (0..100)
+        .map(|i| LightStr::from(format!("item {i}, too long to be stored inline")))
+        .collect();
+    let mut cloned = original.light_clone();
+    cloned.push_back(LightStr::from("extra"));
+
+    // The copied chunk holds refcounted strings, not deep copies
+    assert!(LightStr::ptr_eq(&original[99], &cloned[99]));
+}
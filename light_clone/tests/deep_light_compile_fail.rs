@@ -0,0 +1,9 @@
+//! Compile-fail tests for `LightCloneDeep` on collections of heavy elements.
+
+#![cfg(all(feature = "im", feature = "rpds"))]
+
+#[test]
+fn test_deep_light_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui-deep-light/*.rs");
+}
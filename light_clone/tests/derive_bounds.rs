@@ -0,0 +1,135 @@
+#![deny(private_bounds)]
+
+use light_clone::LightClone;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+fn assert_light_clone<T: LightClone>() {}
+
+// Arc<T> is LightClone for any T, so T needs no bound
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+struct Handle<T> {
+    inner: Arc<T>,
+}
+
+#[test]
+fn test_arc_field_does_not_bound_param() {
+    assert_light_clone::<Handle<Mutex<Vec<u8>>>>();
+
+    let handle = Handle {
+        inner: Arc::new(Mutex::new(vec![1u8, 2, 3])),
+    };
+    let cloned = handle.light_clone();
+    assert!(Arc::ptr_eq(&handle.inner, &cloned.inner));
+}
+
+// PhantomData<T> doesn't require T: LightClone, only the derived Clone's T: Clone
+#[derive(Clone, LightClone)]
+struct Tagged<T> {
+    id: u64,
+    _marker: PhantomData<T>,
+}
+
+#[test]
+fn test_phantom_data_field_does_not_bound_param() {
+    assert_light_clone::<Tagged<String>>();
+
+    let tagged: Tagged<String> = Tagged {
+        id: 3,
+        _marker: PhantomData,
+    };
+    assert_eq!(tagged.light_clone().id, 3);
+}
+
+// Shared references are LightClone for any T
+#[derive(Clone, LightClone)]
+struct View<'a, T> {
+    items: &'a [T],
+}
+
+#[test]
+fn test_reference_field_does_not_bound_param() {
+    assert_light_clone::<View<'static, String>>();
+
+    let items = vec![String::from("a"), String::from("b")];
+    let view = View { items: &items };
+    assert_eq!(view.light_clone().items.len(), 2);
+}
+
+// Fields that hold T directly still require T: LightClone
+#[derive(Clone, LightClone)]
+struct Holder<T> {
+    value: T,
+    values: Option<T>,
+}
+
+#[test]
+fn test_direct_field_bounds_param() {
+    let h = Holder {
+        value: Arc::from("x"),
+        values: None::<Arc<str>>,
+    };
+    let cloned = h.light_clone();
+    assert!(Arc::ptr_eq(&h.value, &cloned.value));
+    assert!(cloned.values.is_none());
+}
+
+// Container-level bound replaces the inferred bounds, keeping private types out of
+// the public impl signature
+#[derive(Clone)]
+struct Private<T>(T);
+
+impl<T: LightClone> LightClone for Private<T> {}
+
+#[derive(Clone, LightClone)]
+#[light_clone(bound = "T: LightClone")]
+pub struct PublicContainer<T> {
+    inner: Private<T>,
+}
+
+#[test]
+fn test_container_bound_override() {
+    let p = PublicContainer {
+        inner: Private(Arc::<str>::from("private")),
+    };
+    let cloned = p.light_clone();
+    assert!(Arc::ptr_eq(&p.inner.0, &cloned.inner.0));
+}
+
+// Field-level bound replaces the bound inferred for that field only
+#[derive(Clone, LightClone)]
+pub struct PublicField<T, U> {
+    #[light_clone(bound = "T: LightClone")]
+    inner: Private<T>,
+    other: U,
+}
+
+#[test]
+fn test_field_bound_override() {
+    let p = PublicField {
+        inner: Private(1u32),
+        other: Arc::<str>::from("other"),
+    };
+    let cloned = p.light_clone();
+    assert_eq!(cloned.inner.0, 1);
+    assert!(Arc::ptr_eq(&p.other, &cloned.other));
+}
+
+// An empty bound removes the inferred bounds entirely
+#[derive(LightClone)]
+#[light_clone(derive_clone, bound = "")]
+struct Unbounded<T> {
+    shared: Arc<Mutex<T>>,
+}
+
+#[test]
+fn test_empty_bound() {
+    assert_light_clone::<Unbounded<Vec<String>>>();
+
+    let u = Unbounded {
+        shared: Arc::new(Mutex::new(vec![String::from("x")])),
+    };
+    let cloned = u.light_clone();
+    assert!(Arc::ptr_eq(&u.shared, &cloned.shared));
+}
@@ -0,0 +1,150 @@
+use light_clone::LightClone;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+// Named struct with generated Clone
+#[derive(LightClone, Debug, PartialEq)]
+#[light_clone(derive_clone)]
+struct Person {
+    id: u64,
+    name: Arc<str>,
+}
+
+#[test]
+fn test_derive_clone_named_struct() {
+    let p = Person {
+        id: 1,
+        name: Arc::from("Alice"),
+    };
+
+    let cloned = p.clone();
+    assert_eq!(p, cloned);
+    assert!(Arc::ptr_eq(&p.name, &cloned.name));
+    assert_eq!(Arc::strong_count(&p.name), 2);
+}
+
+#[test]
+fn test_derive_clone_light_clone_shares_arc() {
+    let p = Person {
+        id: 2,
+        name: Arc::from("Bob"),
+    };
+
+    let cloned = p.light_clone();
+    assert_eq!(cloned.id, 2);
+    assert!(Arc::ptr_eq(&p.name, &cloned.name));
+}
+
+// Tuple struct with generated Clone
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+struct Pair(i32, Arc<str>);
+
+#[test]
+fn test_derive_clone_tuple_struct() {
+    let pair = Pair(42, Arc::from("hello"));
+    let cloned = pair.clone();
+
+    assert_eq!(cloned.0, 42);
+    assert!(Arc::ptr_eq(&pair.1, &cloned.1));
+}
+
+// Unit struct with generated Clone
+#[derive(LightClone, Debug, PartialEq)]
+#[light_clone(derive_clone)]
+struct Marker;
+
+#[test]
+fn test_derive_clone_unit_struct() {
+    let m = Marker;
+    assert_eq!(m.clone(), Marker);
+}
+
+// Enum covering every variant shape
+#[derive(LightClone, Debug, PartialEq)]
+#[light_clone(derive_clone)]
+enum Event {
+    Idle,
+    Moved(i32, i32),
+    Renamed { id: u64, name: Arc<str> },
+}
+
+#[test]
+fn test_derive_clone_enum_unit_variant() {
+    assert_eq!(Event::Idle.clone(), Event::Idle);
+}
+
+#[test]
+fn test_derive_clone_enum_tuple_variant() {
+    let e = Event::Moved(3, 4);
+    assert_eq!(e.clone(), Event::Moved(3, 4));
+}
+
+#[test]
+fn test_derive_clone_enum_struct_variant() {
+    let name: Arc<str> = Arc::from("renamed");
+    let e = Event::Renamed {
+        id: 7,
+        name: Arc::clone(&name),
+    };
+
+    let cloned = e.clone();
+    assert_eq!(e, cloned);
+    assert_eq!(Arc::strong_count(&name), 3); // name + e + cloned
+}
+
+// Empty enum still gets a Clone impl
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+#[allow(dead_code)]
+enum Never {}
+
+#[test]
+fn test_derive_clone_empty_enum() {
+    fn assert_light_clone<T: LightClone>() {}
+    assert_light_clone::<Never>();
+}
+
+// Generic struct with generated Clone
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+struct Wrapper<T> {
+    value: T,
+    _marker: PhantomData<T>,
+}
+
+#[test]
+fn test_derive_clone_generic_struct() {
+    let w = Wrapper {
+        value: Arc::from("generic"),
+        _marker: PhantomData,
+    };
+    let cloned: Wrapper<Arc<str>> = w.clone();
+    assert!(Arc::ptr_eq(&w.value, &cloned.value));
+}
+
+// Nested types that both use the generated Clone
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+struct Outer {
+    inner: Person,
+    tag: Option<Arc<str>>,
+}
+
+#[test]
+fn test_derive_clone_nested() {
+    let outer = Outer {
+        inner: Person {
+            id: 9,
+            name: Arc::from("nested"),
+        },
+        tag: Some(Arc::from("tag")),
+    };
+
+    let cloned = outer.clone();
+    assert_eq!(cloned.inner, outer.inner);
+    assert!(Arc::ptr_eq(
+        outer.tag.as_ref().unwrap(),
+        cloned.tag.as_ref().unwrap()
+    ));
+}
@@ -0,0 +1,129 @@
+use light_clone::{CloneCost, LightClone, LightCopy};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+fn assert_light_copy<T: LightCopy>() {}
+
+/// Light-clones through a `LightCopy` bound, as a lock-free hot path would.
+fn hot_path<T: LightCopy>(value: &T) -> T {
+    value.light_clone()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, LightCopy)]
+struct Tick {
+    price: f64,
+    size: u32,
+    side: Side,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, LightCopy)]
+enum Side {
+    Bid,
+    Ask,
+}
+
+#[test]
+fn test_derive_implements_both_traits() {
+    let tick = Tick {
+        price: 1.5,
+        size: 10,
+        side: Side::Ask,
+    };
+
+    assert_eq!(hot_path(&tick), tick);
+    assert_eq!(tick.lc(), tick);
+    assert_eq!(Tick::COST, CloneCost::COPY);
+    assert_eq!(hot_path(&Side::Bid), Side::Bid);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, LightCopy)]
+struct Pair<T>(T, T);
+
+#[derive(LightCopy)]
+#[allow(dead_code)]
+struct Tagged<T> {
+    marker: PhantomData<T>,
+    id: u64,
+}
+
+// Unlike `#[derive(Clone, Copy)]`, these don't require `T: Copy`
+impl<T> Clone for Tagged<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Tagged<T> {}
+
+#[test]
+fn test_generic_bounds_follow_fields() {
+    assert_light_copy::<Pair<u8>>();
+    assert_light_copy::<Pair<(u8, char)>>();
+    assert_eq!(hot_path(&Pair(1u16, 2)), Pair(1, 2));
+
+    // PhantomData<T> is LightCopy for any T, so no bound on T is inferred
+    assert_light_copy::<Tagged<String>>();
+    assert_eq!(<Tagged<Arc<str>>>::COST, CloneCost::COPY);
+}
+
+#[derive(Debug, PartialEq, LightCopy)]
+#[light_clone(derive_clone)]
+struct Generated {
+    id: u32,
+}
+
+impl Copy for Generated {}
+
+#[test]
+fn test_derive_clone_copies_bitwise() {
+    assert_eq!(Generated { id: 4 }.light_clone(), Generated { id: 4 });
+}
+
+#[derive(Clone, Copy, LightCopy)]
+struct Cursor {
+    ptr: std::ptr::NonNull<u8>,
+    end: std::ops::Bound<usize>,
+}
+
+#[test]
+fn test_derive_with_pointer_and_bound_fields() {
+    let mut byte = 7u8;
+    let cursor = Cursor {
+        ptr: std::ptr::NonNull::from(&mut byte),
+        end: std::ops::Bound::Excluded(4),
+    };
+
+    let copy = hot_path(&cursor);
+    assert_eq!(copy.ptr, cursor.ptr);
+    assert_eq!(copy.end, std::ops::Bound::Excluded(4));
+}
+
+#[test]
+fn test_builtin_impls() {
+    assert_light_copy::<u64>();
+    assert_light_copy::<&'static str>();
+    assert_light_copy::<*const u8>();
+    assert_light_copy::<(u8, f64, bool)>();
+    assert_light_copy::<[u32; 8]>();
+    assert_light_copy::<Option<char>>();
+    assert_light_copy::<Result<u8, ()>>();
+    assert_light_copy::<fn(u8) -> u8>();
+    assert_light_copy::<PhantomData<String>>();
+    assert_light_copy::<std::time::Duration>();
+    assert_light_copy::<std::ptr::NonNull<str>>();
+    assert_light_copy::<std::ops::Bound<u32>>();
+    assert_light_copy::<std::task::Poll<Option<u8>>>();
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_is_light_copy() {
+    assert_light_copy::<uuid::Uuid>();
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_is_light_copy() {
+    assert_light_copy::<chrono::NaiveDate>();
+    assert_light_copy::<chrono::DateTime<chrono::Utc>>();
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+// Simulates a prelude crate that re-exports light_clone under another path
+mod prelude {
+    pub mod deps {
+        pub use light_clone as cheap;
+    }
+}
+
+use prelude::deps::cheap::LightClone;
+
+#[derive(LightClone)]
+#[light_clone(crate = "prelude::deps::cheap", derive_clone)]
+struct Session {
+    id: u64,
+    user: Arc<str>,
+}
+
+#[test]
+fn test_crate_path_struct() {
+    let s = Session {
+        id: 1,
+        user: Arc::from("alice"),
+    };
+
+    let cloned = s.light_clone();
+    assert_eq!(cloned.id, 1);
+    assert!(Arc::ptr_eq(&s.user, &cloned.user));
+}
+
+#[derive(Clone, LightClone)]
+#[light_clone(crate = "crate::prelude::deps::cheap")]
+#[allow(dead_code)]
+enum Status<T> {
+    Idle,
+    Busy(Arc<T>),
+}
+
+#[test]
+fn test_crate_path_generic_enum() {
+    let data = Arc::new(vec![1, 2, 3]);
+    let status = Status::Busy(Arc::clone(&data));
+
+    match status.light_clone() {
+        Status::Busy(cloned) => assert!(Arc::ptr_eq(&cloned, &data)),
+        Status::Idle => panic!("Expected Busy variant"),
+    }
+}
+
+#[derive(prelude::deps::cheap::LightMirror)]
+#[light_mirror(crate = "prelude::deps::cheap")]
+struct SessionDto {
+    user: String,
+}
+
+#[test]
+fn test_crate_path_mirror() {
+    let light = SessionDtoLight::from(SessionDto {
+        user: String::from("alice"),
+    });
+    assert_eq!(light.light_clone().user, "alice");
+    assert_eq!(SessionDto::from(&light).user, "alice");
+}
@@ -0,0 +1,94 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+// Borrowed fields: lifetimes must resolve in the per-field assertions
+#[derive(Clone, LightClone)]
+struct Borrowed<'a> {
+    name: &'a str,
+    bytes: &'a [u8],
+}
+
+#[test]
+fn test_borrowed_fields() {
+    let text = String::from("borrowed");
+    let b = Borrowed {
+        name: &text,
+        bytes: text.as_bytes(),
+    };
+
+    let cloned = b.light_clone();
+    assert_eq!(cloned.name, "borrowed");
+    assert_eq!(cloned.bytes.len(), 8);
+}
+
+// Self-referential fields through Arc
+#[derive(Clone, LightClone)]
+struct Node {
+    value: i32,
+    next: Option<Arc<Self>>,
+}
+
+#[test]
+fn test_self_referential_field() {
+    let tail = Arc::new(Node {
+        value: 2,
+        next: None,
+    });
+    let head = Node {
+        value: 1,
+        next: Some(Arc::clone(&tail)),
+    };
+
+    let cloned = head.light_clone();
+    assert_eq!(cloned.value, 1);
+    assert!(Arc::ptr_eq(cloned.next.as_ref().unwrap(), &tail));
+}
+
+// Associated type fields are bounded on the impl rather than asserted eagerly
+#[derive(Clone, LightClone)]
+struct Peeked<I: Iterator + Clone>
+where
+    I::Item: Clone,
+{
+    iter: I,
+    item: Option<I::Item>,
+}
+
+#[derive(Clone)]
+struct Counter(u32);
+
+impl LightClone for Counter {}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0 += 1;
+        Some(self.0)
+    }
+}
+
+#[test]
+fn test_associated_type_field() {
+    let p = Peeked {
+        iter: Counter(0),
+        item: Some(7),
+    };
+
+    let cloned = p.light_clone();
+    assert_eq!(cloned.iter.0, 0);
+    assert_eq!(cloned.item, Some(7));
+}
+
+// Const generic array fields
+#[derive(Clone, LightClone)]
+struct Buffer<const N: usize> {
+    data: [u8; N],
+}
+
+#[test]
+fn test_const_generic_field() {
+    let b = Buffer { data: [1u8; 4] };
+    let cloned = b.light_clone();
+    assert_eq!(cloned.data, [1, 1, 1, 1]);
+}
@@ -0,0 +1,184 @@
+use light_clone::{LightClone, LightCloneSync};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::thread;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn assert_light_clone_sync<T: LightCloneSync>() {}
+
+/// Light-clones `value` onto another thread and returns what the thread saw.
+fn round_trip<T: LightCloneSync + 'static>(value: &T) -> T {
+    let value = value.light_clone();
+    thread::spawn(move || value).join().unwrap()
+}
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Snapshot {
+    version: u64,
+    body: Arc<str>,
+    tags: Arc<[Arc<str>]>,
+    parent: Option<std::sync::Weak<str>>,
+}
+
+#[test]
+fn test_struct_crosses_threads() {
+    let snapshot = Snapshot {
+        version: 3,
+        body: Arc::from("hello"),
+        tags: Arc::from(vec![Arc::from("a")]),
+        parent: None,
+    };
+
+    let received = round_trip(&snapshot);
+    assert_eq!(received.version, 3);
+    assert!(Arc::ptr_eq(&received.body, &snapshot.body));
+    assert!(Arc::ptr_eq(&received.tags, &snapshot.tags));
+    assert!(received.parent.is_none());
+}
+
+#[derive(Clone, LightClone, LightCloneSync)]
+enum Event {
+    Tick,
+    Message { id: u32, text: Arc<str> },
+    Batch(Arc<[u8]>, (u8, u16)),
+}
+
+#[test]
+fn test_enum_crosses_threads() {
+    let event = Event::Message {
+        id: 7,
+        text: Arc::from("ping"),
+    };
+
+    match round_trip(&event) {
+        Event::Message { id, text } => {
+            assert_eq!(id, 7);
+            assert_eq!(&*text, "ping");
+        }
+        _ => panic!("wrong variant"),
+    }
+    assert!(matches!(round_trip(&Event::Tick), Event::Tick));
+    match round_trip(&Event::Batch(Arc::from(&[1u8][..]), (1, 2))) {
+        Event::Batch(bytes, header) => {
+            assert_eq!(&*bytes, &[1]);
+            assert_eq!(header, (1, 2));
+        }
+        _ => panic!("wrong variant"),
+    }
+}
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Shared<T> {
+    inner: Arc<T>,
+    marker: PhantomData<T>,
+}
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Tagged<T> {
+    value: T,
+    tag: &'static str,
+}
+
+#[test]
+fn test_generic_bounds_follow_fields() {
+    assert_light_clone_sync::<Shared<String>>();
+    assert_light_clone_sync::<Tagged<u64>>();
+    assert_light_clone_sync::<Tagged<Arc<str>>>();
+
+    let tagged = Tagged {
+        value: Arc::<str>::from("x"),
+        tag: "t",
+    };
+    let received = round_trip(&tagged);
+    assert_eq!(&*received.value, "x");
+    assert_eq!(received.tag, "t");
+
+    let shared = Shared {
+        inner: Arc::new(String::from("y")),
+        marker: PhantomData,
+    };
+    assert!(Arc::ptr_eq(&round_trip(&shared).inner, &shared.inner));
+}
+
+// A thread-safe handle from another crate that only implements `LightClone`, so an
+// inferred `Handle<T>: LightCloneSync` bound could never hold
+struct Handle<T>(PhantomData<T>);
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle(PhantomData)
+    }
+}
+
+impl<T> LightClone for Handle<T> {}
+
+// Container-level bound replaces the inferred `LightCloneSync` bounds and leaves the
+// `LightClone` impl with its own
+#[allow(dead_code)]
+#[derive(Clone, LightClone, LightCloneSync)]
+#[light_clone(bound(sync = "T: LightCloneSync"))]
+struct Wrapped<T> {
+    value: T,
+    handle: Handle<T>,
+}
+
+// Field-level bound replaces the bound inferred for that field only
+#[allow(dead_code)]
+#[derive(Clone, LightClone, LightCloneSync)]
+struct PartlyWrapped<T> {
+    value: T,
+    #[light_clone(bound = "")]
+    handle: Handle<T>,
+}
+
+#[test]
+fn test_bound_override() {
+    assert_light_clone_sync::<Wrapped<Arc<str>>>();
+    assert_light_clone_sync::<PartlyWrapped<u64>>();
+
+    // `Rc<str>` isn't `LightCloneSync`, but the `LightClone` impl doesn't ask for it
+    assert_light_clone::<Wrapped<std::rc::Rc<str>>>();
+
+    let wrapped = Wrapped {
+        value: Arc::<str>::from("x"),
+        handle: Handle(PhantomData),
+    };
+    assert!(Arc::ptr_eq(&round_trip(&wrapped).value, &wrapped.value));
+
+    let partly = PartlyWrapped {
+        value: 4u64,
+        handle: Handle(PhantomData),
+    };
+    assert_eq!(round_trip(&partly).value, 4);
+}
+
+#[test]
+fn test_builtin_impls() {
+    assert_light_clone_sync::<u64>();
+    assert_light_clone_sync::<&'static str>();
+    assert_light_clone_sync::<Arc<str>>();
+    assert_light_clone_sync::<std::sync::Weak<str>>();
+    assert_light_clone_sync::<Option<Arc<str>>>();
+    assert_light_clone_sync::<Result<Arc<str>, u8>>();
+    assert_light_clone_sync::<[u8; 16]>();
+    assert_light_clone_sync::<(u8, Arc<str>)>();
+    assert_light_clone_sync::<fn(u8) -> u8>();
+    assert_light_clone_sync::<PhantomData<String>>();
+}
+
+#[cfg(feature = "rpds")]
+#[test]
+fn test_rpds_sync_collections() {
+    assert_light_clone_sync::<rpds::VectorSync<u32>>();
+    assert_light_clone_sync::<rpds::HashTrieMapSync<u32, Arc<str>>>();
+    assert_light_clone_sync::<rpds::RedBlackTreeSetSync<u32>>();
+    assert_light_clone_sync::<rpds::Vector<u32, archery::ArcK>>();
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn test_im_collections() {
+    assert_light_clone_sync::<im::Vector<u32>>();
+    assert_light_clone_sync::<im::HashMap<u32, Arc<str>>>();
+}
@@ -0,0 +1,9 @@
+//! Compile-fail tests for `#[derive(LightCloneSync)]` on rpds collections.
+
+#![cfg(feature = "rpds")]
+
+#[test]
+fn test_rpds_sync_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui-rpds/*.rs");
+}
@@ -0,0 +1,70 @@
+use light_clone::LightClone;
+
+// FFI-style packet header with overlapping views of the same bytes
+#[derive(Clone, Copy, LightClone)]
+#[repr(C)]
+union PacketHeader {
+    raw: [u8; 8],
+    words: [u32; 2],
+    value: u64,
+}
+
+#[test]
+fn test_copy_union() {
+    let header = PacketHeader { value: 0x0102_0304 };
+    let cloned = header.light_clone();
+    assert_eq!(unsafe { cloned.value }, 0x0102_0304);
+    assert_eq!(unsafe { cloned.raw }, unsafe { header.raw });
+    assert_eq!(unsafe { cloned.words }, unsafe { header.words });
+}
+
+// Generic union: LightClone holds whenever the union is Copy
+#[derive(Clone, Copy, LightClone)]
+union Either<A: Copy, B: Copy> {
+    left: A,
+    right: B,
+}
+
+#[test]
+fn test_generic_copy_union() {
+    let e: Either<u32, f32> = Either { left: 7 };
+    let cloned = e.light_clone();
+    assert_eq!(unsafe { cloned.left }, 7);
+
+    let e: Either<u32, f32> = Either { right: 1.5 };
+    assert_eq!(unsafe { e.light_clone().right }, 1.5);
+}
+
+// derive_clone generates the bitwise Clone that Copy requires
+#[derive(Copy, LightClone)]
+#[light_clone(derive_clone)]
+union Register {
+    bits: u32,
+    signed: i32,
+}
+
+#[test]
+fn test_derive_clone_union() {
+    let r = Register { bits: u32::MAX };
+    #[allow(clippy::clone_on_copy)]
+    let cloned = r.clone();
+    assert_eq!(unsafe { cloned.signed }, -1);
+    assert_eq!(unsafe { r.light_clone().bits }, u32::MAX);
+}
+
+#[derive(Copy, LightClone)]
+#[light_clone(derive_clone)]
+union Slot<T: Copy> {
+    value: T,
+    empty: (),
+}
+
+#[test]
+fn test_derive_clone_generic_union() {
+    let slot = Slot { value: 3u8 };
+    let cloned = slot.light_clone();
+    assert_eq!(unsafe { cloned.value }, 3);
+
+    let empty: Slot<u64> = Slot { empty: () };
+    let _ = empty.light_clone();
+}
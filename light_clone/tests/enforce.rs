@@ -0,0 +1,81 @@
+use light_clone::LightClone;
+
+fn assert_light_clone<T: LightClone>() {}
+
+#[light_clone::enforce]
+mod state {
+    use std::sync::Arc;
+
+    #[derive(Clone, Debug)]
+    pub struct Session {
+        pub user: Arc<str>,
+        pub attempts: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Status {
+        Active,
+        Locked { reason: Arc<str> },
+    }
+
+    #[derive(Clone, Copy, light_clone::LightCopy)]
+    pub struct Limits {
+        pub max: u32,
+    }
+
+    #[derive(Clone)]
+    #[light_clone(exempt)]
+    pub struct Draft {
+        pub text: String,
+    }
+
+    // Not `Clone`, so left alone
+    pub struct Buffer {
+        pub bytes: Vec<u8>,
+    }
+
+    #[derive(Clone)]
+    #[light_clone(max_size = 16)]
+    pub struct Small(pub u64);
+
+    pub mod nested {
+        #[derive(Clone)]
+        pub struct Id(pub u64);
+    }
+}
+
+#[test]
+fn test_clone_types_become_light_clone() {
+    assert_light_clone::<state::Session>();
+    assert_light_clone::<state::Status>();
+    assert_light_clone::<state::Limits>();
+    assert_light_clone::<state::Small>();
+    assert_light_clone::<state::nested::Id>();
+
+    let session = state::Session {
+        user: "alice".into(),
+        attempts: 1,
+    };
+    let cloned = session.light_clone();
+    assert!(std::sync::Arc::ptr_eq(&cloned.user, &session.user));
+    assert_eq!(cloned.attempts, 1);
+    assert_eq!(
+        state::Status::Locked { reason: "x".into() }.lc(),
+        state::Status::Locked { reason: "x".into() }
+    );
+    assert_eq!(state::Limits { max: 3 }.lc().max, 3);
+    assert_eq!(state::nested::Id(4).lc().0, 4);
+    assert_eq!(state::Small(5).lc().0, 5);
+    assert_eq!(state::Status::Active.lc(), state::Status::Active);
+}
+
+#[test]
+fn test_exempt_and_non_clone_types_are_untouched() {
+    let draft = state::Draft {
+        text: String::from("wip"),
+    };
+    assert_eq!(draft.clone().text, "wip");
+
+    let buffer = state::Buffer { bytes: vec![1] };
+    assert_eq!(buffer.bytes, [1]);
+}
@@ -121,3 +121,14 @@ fn struct_with_im_vector_field_compiles_and_clones() {
     assert_eq!(&*cloned.name, "Alice");
     assert_eq!(cloned.tags.len(), 2);
 }
+
+#[test]
+fn struct_with_im_vector_field_is_persistent_cost() {
+    use light_clone::{CloneCost, CostTier};
+
+    assert_eq!(<im::Vector<i32>>::COST, CloneCost::PERSISTENT);
+    assert_eq!(
+        PersonWithList::COST,
+        CloneCost::new(CostTier::Persistent, 2)
+    );
+}
@@ -0,0 +1,131 @@
+use light_clone::intern::{intern, InternStats, Interner};
+use light_clone::{LightClone, LightStr};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn global_intern_shares_storage() {
+    let a = intern("global-tenant");
+    let b = intern(&String::from("global-tenant"));
+
+    assert_eq!(a, b);
+    assert!(LightStr::ptr_eq(&a, &b));
+    assert!(LightStr::ptr_eq(
+        &a,
+        &Interner::global().intern("global-tenant")
+    ));
+}
+
+#[test]
+fn equal_strings_are_deduplicated() {
+    let pool = Interner::new();
+    let a = pool.intern("x");
+    let b = pool.intern("x");
+    let c = pool.intern("y");
+
+    assert!(LightStr::ptr_eq(&a, &b));
+    assert!(!LightStr::ptr_eq(&a, &c));
+    assert_ne!(a, c);
+}
+
+#[test]
+fn separate_pools_are_independent() {
+    let first = Interner::new();
+    let second = Interner::new();
+
+    assert!(!LightStr::ptr_eq(
+        &first.intern("shared"),
+        &second.intern("shared")
+    ));
+    assert_eq!(first.stats().misses, 1);
+    assert_eq!(second.stats().misses, 1);
+}
+
+#[test]
+fn stats_count_hits_and_bytes_saved() {
+    let pool = Interner::new();
+    let keys: Vec<LightStr> = ["cpu", "memory", "cpu", "cpu", "memory"]
+        .iter()
+        .map(|k| pool.intern(k))
+        .collect();
+
+    assert_eq!(
+        pool.stats(),
+        InternStats {
+            hits: 3,
+            misses: 2,
+            live_entries: 2,
+            bytes_saved: 3 + 3 + 6,
+        }
+    );
+    drop(keys);
+}
+
+#[test]
+fn unused_strings_are_reclaimed() {
+    let pool = Interner::new();
+    let kept = pool.intern("kept");
+    let dropped = pool.intern("dropped");
+    let clone = dropped.light_clone();
+    assert_eq!(pool.stats().live_entries, 2);
+
+    drop(dropped);
+    assert_eq!(pool.stats().live_entries, 2, "a clone is still alive");
+    drop(clone);
+    assert_eq!(pool.stats().live_entries, 1);
+    assert!(pool.get("dropped").is_none());
+
+    pool.purge();
+    assert!(LightStr::ptr_eq(&pool.get("kept").unwrap(), &kept));
+
+    // Re-interning a reclaimed string allocates again
+    let again = pool.intern("dropped");
+    assert_eq!(again, "dropped");
+    assert_eq!(pool.stats().misses, 3);
+}
+
+#[test]
+fn pool_stays_bounded_by_live_strings() {
+    let pool = Interner::new();
+    for i in 0..10_000 {
+        let _ = pool.intern(&format!("short-lived-{i}"));
+    }
+    assert_eq!(pool.stats().live_entries, 0);
+    assert!(pool.get("short-lived-9999").is_none());
+}
+
+#[test]
+fn get_does_not_insert_or_count() {
+    let pool = Interner::new();
+    assert!(pool.get("missing").is_none());
+
+    let interned = pool.intern("present");
+    assert!(LightStr::ptr_eq(&pool.get("present").unwrap(), &interned));
+    assert_eq!(pool.stats().hits, 0);
+}
+
+#[test]
+fn concurrent_interning_converges_on_one_allocation() {
+    let pool = Arc::new(Interner::new());
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || {
+                (0..100)
+                    .map(|i| pool.intern(&format!("metric-{}", i % 10)))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let results: Vec<Vec<LightStr>> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    for strings in &results {
+        for s in strings {
+            assert!(LightStr::ptr_eq(s, &pool.get(s).unwrap()));
+        }
+    }
+    let stats = pool.stats();
+    assert_eq!(stats.misses, 10);
+    assert_eq!(stats.hits, 790);
+    assert_eq!(stats.live_entries, 10);
+}
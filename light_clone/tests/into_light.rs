@@ -0,0 +1,174 @@
+use light_clone::{FromLight, IntoLight, LightClone, LightOsStr, LightPath, LightSlice, LightStr};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn assert_light<T: LightClone>(value: T) -> T {
+    value.light_clone()
+}
+
+#[test]
+fn strings_become_light_str() {
+    let expected = LightStr::from("text");
+    assert_eq!(String::from("text").into_light(), expected);
+    assert_eq!("text".into_light(), expected);
+    assert_eq!((&String::from("text")).into_light(), expected);
+    assert_eq!(Box::<str>::from("text").into_light(), expected);
+    assert_eq!(Cow::Borrowed("text").into_light(), expected);
+}
+
+#[test]
+fn paths_and_os_strings() {
+    let path: LightPath = PathBuf::from("src/lib.rs").into_light();
+    assert!(path.ends_with("lib.rs"));
+
+    let name: LightOsStr = OsString::from("HOME").into_light();
+    assert_eq!(&*name, "HOME");
+}
+
+#[test]
+fn light_types_convert_to_themselves() {
+    let shared: Arc<str> = Arc::from("shared");
+    assert!(Arc::ptr_eq(&shared.clone().into_light(), &shared));
+
+    let local = Rc::new(5);
+    assert!(Rc::ptr_eq(&local.clone().into_light(), &local));
+
+    let slice: LightSlice<u8> = LightSlice::from([1, 2]);
+    assert!(LightSlice::ptr_eq(&slice.clone().into_light(), &slice));
+
+    assert_eq!(42u64.into_light(), 42);
+    assert_eq!(().into_light(), ());
+}
+
+#[test]
+fn sequences_become_shared_slices() {
+    let tags: Arc<[LightStr]> = vec![String::from("a"), String::from("b")].into_light();
+    assert_eq!(&*tags, [LightStr::from("a"), LightStr::from("b")]);
+
+    let queue: Arc<[u8]> = VecDeque::from([1u8, 2, 3]).into_light();
+    assert_eq!(&*queue, [1, 2, 3]);
+
+    let boxed: Arc<[LightStr]> = vec![String::from("x")].into_boxed_slice().into_light();
+    assert_eq!(boxed[0], "x");
+
+    let array: Arc<[LightStr; 2]> = [String::from("l"), String::from("r")].into_light();
+    assert_eq!(array[1], "r");
+}
+
+#[test]
+fn wrappers_convert_their_contents() {
+    let boxed: Arc<LightStr> = Box::new(String::from("boxed")).into_light();
+    assert_eq!(*boxed, "boxed");
+
+    let some: Option<LightStr> = Some(String::from("some")).into_light();
+    assert_eq!(some.as_deref(), Some("some"));
+
+    let result: Result<u32, LightStr> = Err::<u32, _>(String::from("failed")).into_light();
+    assert_eq!(result, Err(LightStr::from("failed")));
+
+    let (id, name, tags) = (7u32, String::from("seven"), vec![String::from("odd")]).into_light();
+    assert_eq!((id, &*name, &*tags[0]), (7, "seven", "odd"));
+}
+
+#[test]
+fn nested_types_convert_recursively() {
+    let nested: Vec<Option<Vec<String>>> = vec![Some(vec![String::from("deep")]), None];
+    let light: Arc<[Option<Arc<[LightStr]>>]> = nested.into_light();
+
+    assert_eq!(light[0].as_ref().unwrap()[0], "deep");
+    assert!(light[1].is_none());
+    assert_light(light);
+}
+
+#[test]
+fn maps_become_light_maps() {
+    let mut prices = HashMap::new();
+    prices.insert(String::from("book"), vec![String::from("paperback")]);
+    prices.insert(String::from("pen"), vec![]);
+
+    let light = assert_light(prices.into_light());
+    assert_eq!(light.get("book").map(|v| &*v[0]), Some("paperback"));
+    assert_eq!(light.get("pen").map(|v| v.len()), Some(0));
+    assert_eq!(light.iter().count(), 2);
+
+    let ordered = BTreeMap::from([(2u8, String::from("two")), (1, String::from("one"))]);
+    let light = assert_light(ordered.into_light());
+    let values: Vec<&str> = light.values().map(|v| &**v).collect();
+    assert_eq!(values, ["one", "two"]);
+}
+
+#[test]
+fn sets_become_light_sets() {
+    let names = HashSet::from([String::from("ann"), String::from("bo")]);
+    let light = assert_light(names.into_light());
+    assert!(light.contains("ann"));
+    assert!(!light.contains("cy"));
+
+    let ordered = BTreeSet::from([String::from("b"), String::from("a")]);
+    let light = assert_light(ordered.into_light());
+    let items: Vec<&str> = light.iter().map(|s| &**s).collect();
+    assert_eq!(items, ["a", "b"]);
+}
+
+fn round_trip<T: FromLight + Clone>(value: &T) -> T {
+    T::from_light(&value.clone().into_light())
+}
+
+#[test]
+fn from_light_restores_std_values() {
+    let tags = vec![String::from("a"), String::from("b")];
+    assert_eq!(round_trip(&tags), tags);
+
+    let nested = (
+        Some(PathBuf::from("a/b")),
+        [String::from("x")],
+        Box::new(3u8),
+    );
+    assert_eq!(round_trip(&nested), nested);
+
+    let scores = BTreeMap::from([(String::from("ann"), vec![1u8, 2])]);
+    assert_eq!(round_trip(&scores), scores);
+
+    let names = HashSet::from([String::from("ann"), String::from("bo")]);
+    assert_eq!(round_trip(&names), names);
+
+    let result: Result<String, OsString> = Err(OsString::from("bad"));
+    assert_eq!(round_trip(&result), result);
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn im_feature_picks_im_collections() {
+    let map: im::HashMap<LightStr, u8> = HashMap::from([(String::from("k"), 1u8)]).into_light();
+    let set: im::OrdSet<u8> = BTreeSet::from([1u8]).into_light();
+    assert_eq!(map.len() + set.len(), 2);
+}
+
+#[cfg(all(feature = "imbl", not(feature = "im")))]
+#[test]
+fn imbl_feature_picks_imbl_collections() {
+    let map: imbl::HashMap<LightStr, u8> = HashMap::from([(String::from("k"), 1u8)]).into_light();
+    let set: imbl::OrdSet<u8> = BTreeSet::from([1u8]).into_light();
+    assert_eq!(map.len() + set.len(), 2);
+}
+
+#[cfg(all(feature = "rpds", not(any(feature = "im", feature = "imbl"))))]
+#[test]
+fn rpds_feature_picks_thread_safe_rpds_collections() {
+    let map: rpds::HashTrieMapSync<LightStr, u8> =
+        HashMap::from([(String::from("k"), 1u8)]).into_light();
+    let set: rpds::RedBlackTreeSetSync<u8> = BTreeSet::from([1u8]).into_light();
+    assert_eq!(map.size() + set.size(), 2);
+}
+
+#[cfg(not(any(feature = "im", feature = "imbl", feature = "rpds")))]
+#[test]
+fn no_collection_feature_shares_std_collections() {
+    let map: Arc<HashMap<LightStr, u8>> = HashMap::from([(String::from("k"), 1u8)]).into_light();
+    let set: Arc<BTreeSet<u8>> = BTreeSet::from([1u8]).into_light();
+    assert_eq!(map.len() + set.len(), 2);
+}
@@ -0,0 +1,106 @@
+use light_clone::{lc_move, LightStr};
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+
+/// Polls a future that never waits to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWake;
+
+    impl Wake for NoopWake {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWake));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_plain_captures() {
+    let name: Arc<str> = Arc::from("worker");
+    let id = 7u32;
+
+    let describe = lc_move!([name, id] move || format!("{name}#{id}"));
+
+    assert_eq!(describe(), "worker#7");
+    assert_eq!(Arc::strong_count(&name), 2);
+    drop(describe);
+    assert_eq!(Arc::strong_count(&name), 1);
+}
+
+struct Config {
+    endpoint: LightStr,
+}
+
+struct Service {
+    config: Config,
+    label: Rc<str>,
+}
+
+impl Service {
+    fn handler(&self) -> impl Fn() -> String {
+        lc_move!([self.label, endpoint = self.config.endpoint] move || {
+            format!("{label} -> {endpoint}")
+        })
+    }
+}
+
+#[test]
+fn test_field_paths_and_renames() {
+    let service = Service {
+        config: Config {
+            endpoint: LightStr::from("https://example.com"),
+        },
+        label: Rc::from("api"),
+    };
+
+    let handler = service.handler();
+    assert_eq!(handler(), "api -> https://example.com");
+    assert_eq!(Rc::strong_count(&service.label), 2);
+
+    let endpoint_len = lc_move!([service.config.endpoint,] move || endpoint.len());
+    assert_eq!(endpoint_len(), 19);
+}
+
+#[test]
+fn test_captures_cross_threads() {
+    let shared: Arc<[u8]> = Arc::from(&[1, 2, 3][..]);
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| thread::spawn(lc_move!([shared] move || shared[i % 3])))
+        .collect();
+    let sum: u32 = handles
+        .into_iter()
+        .map(|h| u32::from(h.join().unwrap()))
+        .sum();
+
+    assert_eq!(sum, 1 + 2 + 3 + 1);
+    assert_eq!(Arc::strong_count(&shared), 1);
+}
+
+#[test]
+fn test_async_blocks() {
+    let greeting: Arc<str> = Arc::from("hello");
+    let count = 3usize;
+
+    let future = lc_move!([greeting, times = count] async move { greeting.repeat(times) });
+
+    assert_eq!(Arc::strong_count(&greeting), 2);
+    assert_eq!(block_on(future), "hellohellohello");
+    assert_eq!(Arc::strong_count(&greeting), 1);
+}
+
+#[test]
+fn test_empty_capture_list() {
+    let constant = lc_move!([] move || 42);
+    assert_eq!(constant(), 42);
+}
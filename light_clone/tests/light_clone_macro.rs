@@ -0,0 +1,114 @@
+use light_clone::{lc, light_clone, LightClone, LightStr};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Stands in for a third-party `Copy` type that can't get a `LightClone` impl.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+enum Flags {
+    Read = 1,
+    Write = 2,
+}
+
+#[test]
+fn test_copy_without_impl_is_copied() {
+    let position = Vec3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    assert_eq!(light_clone!(position), position);
+    assert_eq!(lc!(position), position);
+    assert_eq!(lc!(Flags::Write), Flags::Write);
+}
+
+#[test]
+fn test_light_clone_types_share_their_allocation() {
+    let name: Arc<str> = Arc::from("player");
+    let local: Rc<str> = Rc::from("local");
+    let text = LightStr::from("text");
+
+    assert!(Arc::ptr_eq(&light_clone!(name), &name));
+    assert!(Rc::ptr_eq(&lc!(local), &local));
+    assert_eq!(lc!(text), text);
+    assert_eq!(Arc::strong_count(&name), 1);
+}
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A `Copy` type whose `Clone` impl is observable, to check which path the macro takes.
+#[derive(Copy)]
+struct Counted(u8);
+
+// Deliberately not `*self`, so the test can tell `clone()` from a plain copy
+#[allow(clippy::non_canonical_clone_impl)]
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.with(|clones| clones.set(clones.get() + 1));
+        *self
+    }
+}
+
+impl LightClone for Counted {}
+
+#[test]
+fn test_prefers_light_clone_over_copy() {
+    let value = Counted(7);
+    let before = CLONES.with(Cell::get);
+
+    let cloned = light_clone!(value);
+
+    assert_eq!(cloned.0, 7);
+    assert_eq!(CLONES.with(Cell::get), before + 1);
+}
+
+struct Player {
+    name: Arc<str>,
+    position: Vec3,
+}
+
+#[test]
+fn test_field_paths_and_expressions() {
+    let player = Player {
+        name: Arc::from("p1"),
+        position: Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+
+    let name = lc!(player.name);
+    let position = lc!(player.position);
+    let first = lc!(vec![Arc::<str>::from("a")][0]);
+
+    assert!(Arc::ptr_eq(&name, &player.name));
+    assert_eq!(position, player.position);
+    assert_eq!(&*first, "a");
+}
+
+fn generic_light<T: LightClone>(value: &T) -> T {
+    light_clone!(*value)
+}
+
+fn generic_copy<T: Copy>(value: &T) -> T {
+    light_clone!(*value)
+}
+
+#[test]
+fn test_generic_bounds() {
+    let name: Arc<str> = Arc::from("g");
+    assert!(Arc::ptr_eq(&generic_light(&name), &name));
+    assert_eq!(generic_copy(&Flags::Read), Flags::Read);
+}
@@ -0,0 +1,86 @@
+use light_clone::{light_format, LightClone, LightStr, ToLightStr};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::Arc;
+
+#[test]
+fn light_format_without_arguments() {
+    let s = light_format!("static text");
+    assert_eq!(s, "static text");
+    assert!(LightStr::ptr_eq(&s, &light_format!("static text")));
+}
+
+#[test]
+fn light_format_short_and_long_results() {
+    let name = "alice";
+    let id = 7;
+
+    assert_eq!(light_format!("{name}:{id}"), "alice:7");
+
+    let long = light_format!("user {name} has id {id:04} and a long description");
+    assert_eq!(long, "user alice has id 0007 and a long description");
+    assert!(LightStr::ptr_eq(&long, &long.light_clone()));
+}
+
+#[test]
+fn light_format_matches_format() {
+    for n in [0usize, 5, 21, 22, 23, 100] {
+        let text = "é".repeat(n);
+        assert_eq!(light_format!("{text}|{n:>3}"), format!("{text}|{n:>3}"));
+    }
+}
+
+#[test]
+fn light_format_heap_result_converts_to_arc_without_copying() {
+    let n = 12345;
+    let s = light_format!("{n} is longer than the inline capacity");
+    let arc: Arc<str> = s.light_clone().into();
+    assert_eq!(&*arc, &*s);
+    assert!(LightStr::ptr_eq(&s, &LightStr::from(arc)));
+}
+
+#[test]
+fn light_format_survives_output_changing_between_passes() {
+    // Writes more on every call, so the measured length is stale on the second pass
+    struct Growing(Cell<usize>);
+
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str(&"ab".repeat(15 * self.0.get()))
+        }
+    }
+
+    let growing = Growing(Cell::new(0));
+    let s = light_format!("{growing}");
+    assert_eq!(s.len() % 2, 0);
+    assert!(s.len() >= 60);
+    assert!(s.chars().all(|c| c == 'a' || c == 'b'));
+}
+
+#[test]
+fn to_light_str_for_display_types() {
+    assert_eq!(42.to_light_str(), "42");
+    assert_eq!('c'.to_light_str(), "c");
+    assert_eq!("borrowed".to_light_str(), "borrowed");
+    assert_eq!(
+        String::from("owned, and long enough for the heap").to_light_str(),
+        "owned, and long enough for the heap"
+    );
+
+    let unsized_str: &str = "unsized";
+    assert_eq!(ToLightStr::to_light_str(unsized_str), "unsized");
+}
+
+#[test]
+fn to_light_str_for_custom_display() {
+    struct Point(i32, i32);
+
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({}, {})", self.0, self.1)
+        }
+    }
+
+    assert_eq!(Point(1, -2).to_light_str(), "(1, -2)");
+}
@@ -0,0 +1,187 @@
+use light_clone::{FromLight, IntoLight, LightClone, LightMirror, LightSlice, LightStr};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+fn assert_light<T: LightClone>(value: T) -> T {
+    value.light_clone()
+}
+
+#[derive(Debug, Clone, PartialEq, LightMirror)]
+#[light_mirror(derive(Debug, PartialEq))]
+pub struct AddressDto {
+    pub street: String,
+    pub city: String,
+}
+
+#[derive(Debug, Clone, PartialEq, LightMirror)]
+#[light_mirror(derive(Debug))]
+pub struct UserDto {
+    pub id: u64,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub address: AddressDto,
+    pub previous: Vec<AddressDto>,
+    pub scores: BTreeMap<String, u32>,
+    pub nickname: Option<String>,
+}
+
+fn user() -> UserDto {
+    let address = AddressDto {
+        street: String::from("1 Main St"),
+        city: String::from("Springfield"),
+    };
+    UserDto {
+        id: 7,
+        name: String::from("Ann"),
+        tags: vec![String::from("admin"), String::from("ops")],
+        address: address.clone(),
+        previous: vec![address],
+        scores: BTreeMap::from([(String::from("chess"), 1200)]),
+        nickname: None,
+    }
+}
+
+#[test]
+fn fields_map_to_light_equivalents() {
+    let light = assert_light(UserDtoLight::from(user()));
+
+    let name: &LightStr = &light.name;
+    assert_eq!(name, "Ann");
+    let tags: &Arc<[LightStr]> = &light.tags;
+    assert_eq!(&**tags, ["admin", "ops"]);
+    assert_eq!(light.nickname, None);
+    assert_eq!(light.scores.get("chess"), Some(&1200));
+}
+
+#[test]
+fn nested_mirrored_types_map_to_their_twins() {
+    let light = UserDtoLight::from(user());
+
+    let address: &AddressDtoLight = &light.address;
+    assert_eq!(address.city, "Springfield");
+    let previous: &Arc<[AddressDtoLight]> = &light.previous;
+    assert_eq!(previous[0], *address);
+}
+
+#[test]
+fn converts_back_to_the_original() {
+    let original = user();
+    let light = original.clone().into_light();
+    assert_eq!(UserDto::from(&light), original);
+    assert_eq!(UserDto::from_light(&light), original);
+}
+
+#[test]
+fn twin_clones_share_data() {
+    let light = UserDtoLight::from(user());
+    let copy = light.clone();
+    assert!(Arc::ptr_eq(&light.tags, &copy.tags));
+    assert!(Arc::ptr_eq(&light.previous, &copy.previous));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Checksum(u32);
+
+impl From<u32> for Checksum {
+    fn from(value: u32) -> Self {
+        Checksum(value)
+    }
+}
+
+impl From<Checksum> for u32 {
+    fn from(value: Checksum) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, PartialEq, LightMirror)]
+struct Document {
+    #[light_mirror(keep)]
+    owner: Arc<str>,
+    #[light_mirror(into = "LightSlice<u8>")]
+    body: Vec<u8>,
+    #[light_mirror(into = "u32")]
+    checksum: Checksum,
+    #[light_mirror(skip)]
+    scratch: Vec<String>,
+}
+
+#[test]
+fn field_attributes_control_the_mapping() {
+    let owner: Arc<str> = Arc::from("ann");
+    let document = Document {
+        owner: owner.clone(),
+        body: vec![1, 2, 3],
+        checksum: Checksum(6),
+        scratch: vec![String::from("draft")],
+    };
+
+    let light = assert_light(DocumentLight::from(document));
+    assert!(Arc::ptr_eq(&light.owner, &owner));
+    let body: &LightSlice<u8> = &light.body;
+    assert_eq!(body, &[1, 2, 3]);
+    assert_eq!(light.checksum, 6);
+
+    let back = Document::from(&light);
+    assert_eq!(back.body, [1, 2, 3]);
+    assert_eq!(back.checksum, Checksum(6));
+    assert!(back.scratch.is_empty());
+}
+
+#[derive(Debug, PartialEq, LightMirror)]
+#[light_mirror(name = "LightEvent", derive(Debug, PartialEq))]
+enum Event {
+    /// Nothing happened
+    Idle,
+    Message(String, #[light_mirror(skip)] u8),
+    Moved {
+        from: String,
+        to: String,
+    },
+}
+
+#[test]
+fn enums_mirror_every_variant() {
+    assert_eq!(LightEvent::from(Event::Idle), LightEvent::Idle);
+
+    let light = assert_light(LightEvent::from(Event::Message(String::from("hi"), 3)));
+    assert_eq!(light, LightEvent::Message(LightStr::from("hi")));
+    assert_eq!(Event::from(&light), Event::Message(String::from("hi"), 0));
+
+    let moved = Event::Moved {
+        from: String::from("a"),
+        to: String::from("b"),
+    };
+    let light = LightEvent::from(moved);
+    assert!(matches!(&light, LightEvent::Moved { to, .. } if to == "b"));
+    assert!(matches!(Event::from(&light), Event::Moved { from, .. } if from == "a"));
+}
+
+#[derive(Debug, PartialEq, LightMirror)]
+struct Page<T> {
+    items: Vec<T>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, PartialEq, LightMirror)]
+struct Wrapper(String, #[light_mirror(keep)] u8);
+
+#[derive(Debug, PartialEq, LightMirror)]
+struct Marker;
+
+#[test]
+fn generic_tuple_and_unit_structs() {
+    let page = Page {
+        items: vec![String::from("x")],
+        cursor: Some(String::from("next")),
+    };
+    let light: PageLight<String> = assert_light(page.into_light());
+    assert_eq!(&*light.items, ["x"]);
+    assert_eq!(Page::from_light(&light).cursor.as_deref(), Some("next"));
+
+    let wrapper = WrapperLight::from(Wrapper(String::from("w"), 1));
+    assert_eq!(wrapper.0, "w");
+    assert_eq!(Wrapper::from(&wrapper), Wrapper(String::from("w"), 1));
+
+    assert_eq!(Marker::from(&MarkerLight::from(Marker)), Marker);
+}
@@ -0,0 +1,80 @@
+use light_clone::{
+    IntoLightOsStr, IntoLightPath, LightClone, LightCloneSync, LightOsStr, LightPath, LightPathExt,
+};
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[test]
+fn light_path_is_light_clone_sync() {
+    fn assert_sync<T: LightCloneSync>() {}
+    assert_sync::<LightPath>();
+    assert_sync::<LightOsStr>();
+
+    let path: LightPath = "build/out".into_light_path();
+    let copy = path.light_clone();
+    assert!(Arc::ptr_eq(&path, &copy));
+}
+
+#[test]
+fn into_light_path_conversions() {
+    let expected = Path::new("src/lib.rs");
+    let owned = PathBuf::from("src/lib.rs");
+
+    assert_eq!(&*expected.into_light_path(), expected);
+    assert_eq!(&*(&owned).into_light_path(), expected);
+    assert_eq!(&*owned.into_light_path(), expected);
+    assert_eq!(&*"src/lib.rs".into_light_path(), expected);
+    assert_eq!(&*String::from("src/lib.rs").into_light_path(), expected);
+
+    let path: LightPath = "src".into_light_path();
+    assert!(Arc::ptr_eq(&path, &path.clone().into_light_path()));
+}
+
+#[test]
+fn into_light_os_str_conversions() {
+    let expected = OsStr::new("HOME");
+    let owned = OsString::from("HOME");
+
+    assert_eq!(&*expected.into_light_os_str(), expected);
+    assert_eq!(&*(&owned).into_light_os_str(), expected);
+    assert_eq!(&*owned.into_light_os_str(), expected);
+    assert_eq!(&*"HOME".into_light_os_str(), expected);
+    assert_eq!(&*String::from("HOME").into_light_os_str(), expected);
+
+    let name: LightOsStr = "USER".into_light_os_str();
+    assert!(Arc::ptr_eq(&name, &name.clone().into_light_os_str()));
+}
+
+#[test]
+fn join_and_parent_return_light_paths() {
+    let root: LightPath = "/srv/app".into_light_path();
+    let config: LightPath = root.light_join("config").light_join("app.toml");
+
+    assert_eq!(&*config, Path::new("/srv/app/config/app.toml"));
+    assert_eq!(
+        config.light_parent().and_then(|p| p.light_parent()),
+        Some(root)
+    );
+    assert_eq!(Path::new("/").light_parent(), None);
+    assert_eq!(PathBuf::from("a").light_join("b"), "a/b".into_light_path());
+}
+
+#[test]
+fn light_path_in_derived_struct() {
+    #[derive(Clone, LightClone, Debug, PartialEq)]
+    struct WatchEvent {
+        path: LightPath,
+        file_name: Option<LightOsStr>,
+    }
+
+    let path: LightPath = "src/main.rs".into_light_path();
+    let event = WatchEvent {
+        file_name: path.file_name().map(IntoLightOsStr::into_light_os_str),
+        path,
+    };
+
+    let copy = event.light_clone();
+    assert_eq!(copy, event);
+    assert_eq!(copy.file_name.as_deref(), Some(OsStr::new("main.rs")));
+}
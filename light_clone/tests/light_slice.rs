@@ -0,0 +1,164 @@
+use light_clone::{IntoLightSlice, LightBytes, LightClone, LightCloneSync, LightSlice};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// Counts allocations made by the current thread, so parallel tests don't interfere
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn collect_exact_size_allocates_once() {
+    let (slice, allocations) =
+        allocations_during(|| (0..1000).map(|i| i * 2).collect::<LightSlice<u64>>());
+    assert_eq!(allocations, 1);
+    assert_eq!(slice.len(), 1000);
+    assert_eq!(slice[999], 1998);
+}
+
+#[test]
+fn collect_unknown_size() {
+    let evens: LightSlice<u32> = (0..20).filter(|i| i % 2 == 0).collect();
+    assert_eq!(evens, [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+
+    let empty: LightSlice<u32> = std::iter::empty().collect();
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "fewer items than its exact size hint")]
+fn collect_rejects_a_lying_size_hint() {
+    struct Liar(u32);
+
+    impl Iterator for Liar {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(self.0)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (5, Some(5))
+        }
+    }
+
+    let _: LightSlice<u32> = Liar(2).collect();
+}
+
+#[test]
+fn clone_shares_the_allocation() {
+    let shared: Arc<[Rc<u8>]> = Arc::from(vec![Rc::new(1), Rc::new(2)]);
+    let slice = LightSlice::from(Arc::clone(&shared));
+    let copy = slice.light_clone();
+
+    assert!(LightSlice::ptr_eq(&slice, &copy));
+    assert_eq!(Arc::strong_count(&shared), 3);
+    // The elements themselves are not cloned
+    assert_eq!(Rc::strong_count(&slice[0]), 1);
+}
+
+#[test]
+fn sub_slices_share_the_parent() {
+    let data: LightSlice<i32> = vec![1, 2, 3, 4, 5].into_light_slice();
+    let (middle, allocations) = allocations_during(|| data.slice(1..4));
+
+    assert_eq!(allocations, 0);
+    assert_eq!(middle, [2, 3, 4]);
+    assert_eq!(middle.slice(1..), [3, 4]);
+    assert_eq!(data.slice(..=1), [1, 2]);
+    assert_eq!(data.slice(..), data);
+    assert!(data.slice(5..).is_empty());
+    assert!(!LightSlice::ptr_eq(&data, &middle));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds for a slice of length 3")]
+fn slice_panics_out_of_bounds() {
+    let data = LightSlice::from([1, 2, 3]);
+    let _ = data.slice(2..5);
+}
+
+#[test]
+fn into_light_slice_conversions() {
+    let vec = vec![1u8, 2, 3];
+    assert_eq!((&vec).into_light_slice(), [1, 2, 3]);
+    assert_eq!(vec.clone().into_light_slice(), [1, 2, 3]);
+    assert_eq!(vec.as_slice().into_light_slice(), [1, 2, 3]);
+    assert_eq!([1u8, 2, 3].into_light_slice(), vec);
+    assert_eq!((&[1u8, 2, 3]).into_light_slice(), vec);
+    assert_eq!(vec.clone().into_boxed_slice().into_light_slice(), vec);
+
+    let arc: Arc<[u8]> = Arc::from(vec);
+    let slice = Arc::clone(&arc).into_light_slice();
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert!(LightSlice::ptr_eq(
+        &slice,
+        &slice.clone().into_light_slice()
+    ));
+}
+
+#[test]
+fn converts_back_to_arc_and_vec() {
+    let arc: Arc<[u16]> = Arc::from([10, 20, 30]);
+    let whole: Arc<[u16]> = LightSlice::from(Arc::clone(&arc)).into();
+    assert!(Arc::ptr_eq(&arc, &whole));
+
+    let part: Arc<[u16]> = LightSlice::from(Arc::clone(&arc)).slice(1..).into();
+    assert_eq!(&*part, [20, 30]);
+
+    let vec: Vec<u16> = LightSlice::from(arc).into();
+    assert_eq!(vec, [10, 20, 30]);
+}
+
+#[test]
+fn compares_and_hashes_like_a_slice() {
+    let mut map = HashMap::new();
+    map.insert(LightSlice::from([1, 2]), "pair");
+    assert_eq!(map.get(&[1, 2][..]), Some(&"pair"));
+
+    let long = LightSlice::from([1, 2, 3]);
+    assert_eq!(long.slice(..2), LightSlice::from([1, 2]));
+    assert!(long.slice(..2) < long);
+    assert_eq!(format!("{:?}", long), "[1, 2, 3]");
+    assert_eq!(LightSlice::<u8>::default(), LightSlice::from([]));
+}
+
+#[test]
+fn light_bytes_views() {
+    fn assert_sync<T: LightCloneSync>() {}
+    assert_sync::<LightBytes>();
+
+    let packet: LightBytes = b"\x02\x00hello".into_light_slice();
+    let body = packet.slice(2..);
+    assert_eq!(&*body, b"hello");
+    assert_eq!(packet.iter().map(|b| *b as u32).sum::<u32>(), 2 + 532);
+    for byte in &body {
+        assert!(byte.is_ascii_lowercase());
+    }
+}
@@ -0,0 +1,126 @@
+use light_clone::{LightClone, LightCloneSync, LightStr};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+const LONG: &str = "a string long enough to be stored on the heap";
+
+#[test]
+fn light_str_from_static_is_const() {
+    const GREETING: LightStr = LightStr::from_static("hello");
+    assert_eq!(GREETING, "hello");
+    assert!(LightStr::ptr_eq(&GREETING, &GREETING.light_clone()));
+}
+
+#[test]
+fn light_str_implements_light_clone() {
+    fn assert_sync<T: LightCloneSync>() {}
+    assert_sync::<LightStr>();
+
+    let s = LightStr::from("hello");
+    let cloned = s.light_clone();
+    assert_eq!(s, cloned);
+}
+
+#[test]
+fn light_str_heap_clone_shares_allocation() {
+    let shared: Arc<str> = Arc::from(LONG);
+    let s = LightStr::from(shared.clone());
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    let s2 = s.light_clone();
+    assert_eq!(Arc::strong_count(&shared), 3);
+    assert!(LightStr::ptr_eq(&s, &s2));
+}
+
+#[test]
+fn light_str_long_strings_share_allocation() {
+    let s = LightStr::from(LONG.to_string());
+    assert!(LightStr::ptr_eq(&s, &s.clone()));
+}
+
+#[test]
+fn light_str_inline_strings_do_not_share() {
+    let s = LightStr::from("short");
+    assert!(!LightStr::ptr_eq(&s, &s.clone()));
+    assert_eq!(s, s.clone());
+}
+
+#[test]
+fn light_str_can_be_used_in_derived_struct() {
+    #[derive(Clone, LightClone, Debug, PartialEq)]
+    struct Person {
+        name: LightStr,
+        email: LightStr,
+    }
+
+    let person = Person {
+        name: LightStr::from_static("Alice"),
+        email: LightStr::from("alice@example.com"),
+    };
+
+    let cloned = person.light_clone();
+    assert_eq!(person, cloned);
+}
+
+#[test]
+fn light_str_derefs_to_str() {
+    let s = LightStr::from("hello world");
+
+    assert!(s.contains("world"));
+    assert_eq!(s.len(), 11);
+    assert_eq!(&s[0..5], "hello");
+    assert_eq!(s.as_str(), "hello world");
+}
+
+#[test]
+fn light_str_equality_ignores_storage() {
+    let inline = LightStr::from("hello");
+    let stat = LightStr::from_static("hello");
+    let heap = LightStr::from(Arc::<str>::from("hello"));
+
+    assert_eq!(inline, stat);
+    assert_eq!(stat, heap);
+    assert_eq!(inline, "hello");
+    assert_eq!("hello", heap);
+    assert_eq!(String::from("hello"), stat);
+}
+
+#[test]
+fn light_str_hash_map_lookup_by_str() {
+    let mut map = HashMap::new();
+    map.insert(LightStr::from("inline"), 1);
+    map.insert(LightStr::from(LONG), 2);
+    map.insert(LightStr::from_static("static"), 3);
+
+    assert_eq!(map.get("inline"), Some(&1));
+    assert_eq!(map.get(LONG), Some(&2));
+    assert_eq!(map.get("static"), Some(&3));
+}
+
+#[test]
+fn light_str_orders_like_str() {
+    let set: BTreeSet<LightStr> = ["b", "a", LONG].into_iter().map(LightStr::from).collect();
+    let sorted: Vec<&str> = set.iter().map(LightStr::as_str).collect();
+    assert_eq!(sorted, ["a", LONG, "b"]);
+}
+
+#[test]
+fn light_str_display_debug_and_parse() {
+    let s: LightStr = "quoted".parse().unwrap();
+    assert_eq!(s.to_string(), "quoted");
+    assert_eq!(format!("{s:?}"), "\"quoted\"");
+    assert_eq!(LightStr::default(), "");
+}
+
+#[test]
+fn light_str_converts_back_to_arc_and_string() {
+    let shared: Arc<str> = Arc::from(LONG);
+    let back: Arc<str> = LightStr::from(shared.clone()).into();
+    assert!(Arc::ptr_eq(&shared, &back));
+
+    let short: Arc<str> = LightStr::from("short").into();
+    assert_eq!(&*short, "short");
+
+    let owned: String = LightStr::from_static("owned").into();
+    assert_eq!(owned, "owned");
+}
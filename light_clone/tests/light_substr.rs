@@ -0,0 +1,147 @@
+use light_clone::{IntoLightStr, LightClone, LightStr, LightStrArena, LightSubstr};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const TEXT: &str = "name=alice; role=admin; team=platform";
+
+fn heap_text() -> (Arc<str>, LightStr) {
+    let shared: Arc<str> = Arc::from(TEXT);
+    (shared.clone(), LightStr::from(shared))
+}
+
+#[test]
+fn substr_shares_parent_allocation() {
+    let (shared, text) = heap_text();
+    let name = text.substr(5..10);
+
+    assert_eq!(name, "alice");
+    assert_eq!(name.range(), 5..10);
+    assert!(LightStr::ptr_eq(name.parent(), &text));
+    assert_eq!(Arc::strong_count(&shared), 3);
+
+    let cloned = name.light_clone();
+    assert_eq!(Arc::strong_count(&shared), 4);
+    assert_eq!(cloned, name);
+}
+
+#[test]
+fn substr_accepts_any_range() {
+    let text = LightStr::from("hello");
+    assert_eq!(text.substr(..), "hello");
+    assert_eq!(text.substr(1..), "ello");
+    assert_eq!(text.substr(..=1), "he");
+    assert_eq!(text.substr(2..2), "");
+}
+
+#[test]
+#[should_panic(expected = "not on a char boundary")]
+fn substr_panics_inside_a_char() {
+    let text = LightStr::from("héllo");
+    let _ = text.substr(..2);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn substr_panics_out_of_bounds() {
+    let text = LightStr::from("short");
+    let _ = text.substr(..10);
+}
+
+#[test]
+fn substr_from_slices_of_the_parent() {
+    let (_, text) = heap_text();
+    let fields: Vec<LightSubstr> = text
+        .split("; ")
+        .map(|field| text.substr_from(field))
+        .collect();
+
+    assert_eq!(fields, ["name=alice", "role=admin", "team=platform"]);
+    assert!(fields.iter().all(|f| LightStr::ptr_eq(f.parent(), &text)));
+}
+
+#[test]
+fn try_substr_from_rejects_foreign_strings() {
+    let text = LightStr::from("inside");
+    let other = String::from("inside");
+    assert!(text.try_substr_from(&other).is_none());
+    assert_eq!(text.try_substr_from(&text[2..]).unwrap(), "side");
+}
+
+#[test]
+fn nested_substr_keeps_the_original_parent() {
+    let (_, text) = heap_text();
+    let role = text.substr(12..22);
+    let value = role.substr(5..);
+
+    assert_eq!(value, "admin");
+    assert_eq!(value.range(), 17..22);
+    assert!(LightStr::ptr_eq(value.parent(), &text));
+}
+
+#[test]
+fn static_parent_slices_are_promoted_for_free() {
+    let text = LightStr::from_static(TEXT);
+    let name = text.substr(5..10);
+    let nested = text.substr(..10).substr(5..);
+
+    assert_eq!(nested, "alice");
+    assert_eq!(name.clone().into_light_str(), "alice");
+    assert!(LightStr::ptr_eq(
+        &name.into_light_str(),
+        &nested.into_light_str()
+    ));
+}
+
+#[test]
+fn into_light_str_reuses_a_full_view() {
+    let (_, text) = heap_text();
+    let full = LightSubstr::from(text.light_clone());
+    assert!(LightStr::ptr_eq(&full.into_light_str(), &text));
+
+    let part: LightStr = text.substr(..4).into();
+    assert_eq!(part, "name");
+    assert!(!LightStr::ptr_eq(&part, &text));
+}
+
+#[test]
+fn substr_compares_and_hashes_like_str() {
+    let (_, text) = heap_text();
+    let mut map = HashMap::new();
+    map.insert(text.substr(5..10), 1);
+
+    assert_eq!(map.get("alice"), Some(&1));
+    assert_eq!(text.substr(5..10), LightStr::from("alice"));
+    assert_eq!(format!("{}", text.substr(..4)), "name");
+    assert_eq!(format!("{:?}", text.substr(..4)), "\"name\"");
+    assert!(text.substr(..4) < text.substr(12..16));
+}
+
+#[test]
+fn arena_hands_out_views_of_one_buffer() {
+    let mut arena = LightStrArena::with_capacity(64);
+    assert!(arena.is_empty());
+
+    let words = [
+        "first",
+        "second",
+        "a third string to push the buffer onto the heap",
+    ];
+    let indices: Vec<usize> = words.iter().map(|w| arena.push(w)).collect();
+    arena.extend(["", "last"]);
+    assert_eq!(arena.len(), 5);
+
+    let strings = arena.finish();
+    for (index, word) in indices.into_iter().zip(words) {
+        assert_eq!(strings[index], word);
+    }
+    assert_eq!(strings[3], "");
+    assert_eq!(strings[4], "last");
+    assert!(strings
+        .iter()
+        .all(|s| LightStr::ptr_eq(s.parent(), strings[0].parent())));
+}
+
+#[test]
+fn empty_arena_finishes_empty() {
+    assert!(LightStrArena::new().finish().is_empty());
+}
@@ -0,0 +1,112 @@
+use light_clone::no_heavy_clone;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Request {
+    user: Arc<str>,
+    body: String,
+    retries: u8,
+}
+
+#[no_heavy_clone]
+fn light_fields(request: &Request) -> (Arc<str>, u8) {
+    (request.user.clone(), request.retries.clone())
+}
+
+#[test]
+fn test_light_clones_compile_unchanged() {
+    let request = Request {
+        user: Arc::from("alice"),
+        body: String::from("payload"),
+        retries: 3,
+    };
+
+    let (user, retries) = light_fields(&request);
+    assert!(Arc::ptr_eq(&user, &request.user));
+    assert_eq!(retries, 3);
+}
+
+#[no_heavy_clone]
+fn through_references<'a>(shared: &Arc<str>, nested: &&'a Rc<str>) -> (Arc<str>, &'a Rc<str>) {
+    // Like `Clone::clone`, this auto-derefs `&Arc<str>` to the `Arc`, while `&&Rc<str>`
+    // clones the inner reference
+    let closure = |value: &Arc<str>| value.clone();
+    (closure(shared), nested.clone())
+}
+
+#[test]
+fn test_receivers_resolve_like_clone() {
+    let shared: Arc<str> = Arc::from("a");
+    let local: Rc<str> = Rc::from("b");
+
+    let (a, b) = through_references(&shared, &&local);
+    assert!(Arc::ptr_eq(&a, &shared));
+    assert!(std::ptr::eq(b, &local));
+    assert_eq!(Rc::strong_count(&local), 1);
+}
+
+#[no_heavy_clone]
+fn intentional_copies(request: &Request) -> (String, String, String) {
+    #[allow_heavy_clone]
+    let first = request.body.clone();
+
+    let mut second = String::new();
+    #[allow_heavy_clone]
+    second.clone_from(&request.body.clone());
+
+    (first, second, copy_body(request))
+}
+
+#[no_heavy_clone]
+mod handlers {
+    use super::Request;
+    use std::sync::Arc;
+
+    pub fn user(request: &Request) -> Arc<str> {
+        request.user.clone()
+    }
+
+    #[allow_heavy_clone]
+    pub fn body(request: &Request) -> String {
+        request.body.clone()
+    }
+}
+
+fn copy_body(request: &Request) -> String {
+    request.body.clone()
+}
+
+#[test]
+fn test_allow_heavy_clone_escape_hatch() {
+    let request = Request {
+        user: Arc::from("bob"),
+        body: String::from("data"),
+        retries: 0,
+    };
+
+    let (first, second, third) = intentional_copies(&request);
+    assert_eq!(first, "data");
+    assert_eq!(second, "data");
+    assert_eq!(third, "data");
+    assert!(Arc::ptr_eq(&handlers::user(&request), &request.user));
+    assert_eq!(handlers::body(&request), "data");
+}
+
+struct Session {
+    token: Arc<str>,
+}
+
+#[no_heavy_clone]
+impl Session {
+    fn token(&self) -> Arc<str> {
+        self.token.clone()
+    }
+}
+
+#[test]
+fn test_impl_blocks() {
+    let session = Session {
+        token: Arc::from("t"),
+    };
+    assert!(Arc::ptr_eq(&session.token(), &session.token));
+}
@@ -100,3 +100,37 @@ fn rpds_hash_trie_map_operations() {
     assert_eq!(m.get("key1"), cloned.get("key1"));
     assert_eq!(m.get("key2"), cloned.get("key2"));
 }
+
+// Thread-safe collections shared across threads
+#[derive(Clone, LightClone)]
+struct SharedIndex {
+    names: rpds::VectorSync<light_clone::LightStr>,
+    ids: rpds::HashTrieMapSync<u32, light_clone::LightStr>,
+    order: rpds::RedBlackTreeMapSync<u32, u32>,
+}
+
+#[test]
+fn struct_with_sync_fields_is_shared_across_threads() {
+    let index = SharedIndex {
+        names: rpds::VectorSync::new_sync().push_back("alice".into()),
+        ids: rpds::HashTrieMapSync::new_sync().insert(1, "alice".into()),
+        order: rpds::RedBlackTreeMapSync::new_sync().insert(1, 0),
+    };
+
+    let handle = {
+        let index = index.light_clone();
+        std::thread::spawn(move || index.ids.get(&1).map(|name| name.to_string()))
+    };
+
+    assert_eq!(handle.join().unwrap().as_deref(), Some("alice"));
+    assert_eq!(index.names.len(), 1);
+    assert_eq!(index.order.get(&1), Some(&0));
+}
+
+#[test]
+fn rc_and_arc_flavours_implement_light_clone() {
+    let rc: rpds::Vector<i32, archery::RcK> = rpds::Vector::new_with_ptr_kind().push_back(1);
+    let arc: rpds::Vector<i32, archery::ArcTK> = rpds::Vector::new_with_ptr_kind().push_back(1);
+    assert_eq!(rc.light_clone(), rc);
+    assert_eq!(arc.light_clone(), arc);
+}
@@ -0,0 +1,10 @@
+use light_clone::LightCloneDeep;
+
+fn assert_light_clone_deep<T: LightCloneDeep>() {}
+
+fn main() {
+    // Mutating a clone would deep-copy the `String`s in the modified chunks
+    assert_light_clone_deep::<im::Vector<String>>();
+    assert_light_clone_deep::<im::HashMap<u32, Vec<u8>>>();
+    assert_light_clone_deep::<rpds::HashTrieMap<String, u32>>();
+}
@@ -0,0 +1,9 @@
+use light_clone::{LightClone, LightCloneSync};
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Index {
+    entries: rpds::Vector<u32>,
+    sync_entries: rpds::VectorSync<u32>,
+}
+
+fn main() {}
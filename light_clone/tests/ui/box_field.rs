@@ -0,0 +1,8 @@
+use light_clone::LightClone;
+
+#[derive(Clone, LightClone)]
+struct BadStruct {
+    config: Box<u64>, // Box deep-copies its contents
+}
+
+fn main() {}
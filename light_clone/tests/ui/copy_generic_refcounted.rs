@@ -0,0 +1,11 @@
+use light_clone::LightCopy;
+use std::rc::Rc;
+
+#[derive(Clone, LightCopy)]
+struct Pair<T>(T, T);
+
+fn assert_light_copy<T: LightCopy>() {}
+
+fn main() {
+    assert_light_copy::<Pair<Rc<str>>>();
+}
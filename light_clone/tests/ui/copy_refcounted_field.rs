@@ -0,0 +1,14 @@
+use light_clone::LightCopy;
+use std::sync::Arc;
+
+#[derive(Clone, Copy)]
+struct External(u32);
+
+#[derive(Clone, LightCopy)]
+struct Tick {
+    price: f64,
+    venue: Arc<str>,
+    external: External,
+}
+
+fn main() {}
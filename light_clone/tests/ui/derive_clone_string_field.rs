@@ -0,0 +1,9 @@
+use light_clone::LightClone;
+
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+struct BadStruct {
+    name: String, // String does not implement LightClone
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+#[light_clone::enforce]
+mod state {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    pub struct Session {
+        pub user: Arc<str>,
+        pub name: String,
+        pub scores: HashMap<String, u32>,
+    }
+
+    #[derive(Clone)]
+    pub enum Event {
+        Idle,
+        Message(Vec<u8>),
+    }
+
+    #[derive(Clone)]
+    pub struct Counter {
+        pub hits: std::cell::RefCell<u32>,
+    }
+}
+
+fn main() {
+    let _ = Arc::<str>::from("");
+}
@@ -0,0 +1,7 @@
+#[light_clone::enforce]
+#[derive(Clone)]
+struct Session {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+#[derive(Clone, LightClone)]
+struct Pair<T> {
+    shared: Arc<T>,
+    owned: T, // T must be LightClone because it is held directly
+}
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    assert_light_clone::<Pair<String>>();
+}
@@ -0,0 +1,10 @@
+use light_clone::LightClone;
+use std::collections::HashMap;
+
+#[derive(Clone, LightClone)]
+struct BadStruct {
+    id: u64,
+    lookup: HashMap<u64, u64>, // HashMap does not implement LightClone
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use light_clone::LightClone;
+
+#[derive(Clone, LightClone)]
+#[light_clone(bound = "T LightClone")]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(Clone, LightClone)]
+#[light_clone(bound(copy = "T: LightClone"))]
+struct PerImpl<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use light_clone::lc_move;
+use std::sync::Arc;
+
+fn main() {
+    let name: Arc<str> = Arc::from("worker");
+    let buffer = String::from("deep");
+
+    let _task = lc_move!([name, buffer] move || format!("{name}{buffer}"));
+}
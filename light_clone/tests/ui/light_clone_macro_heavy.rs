@@ -0,0 +1,6 @@
+use light_clone::light_clone;
+
+fn main() {
+    let name = String::from("player");
+    let _copy = light_clone!(name);
+}
@@ -0,0 +1,12 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+#[derive(Clone, LightClone)]
+#[light_clone(max_size = 16)]
+struct Message {
+    id: u64,
+    topic: Arc<str>,
+    payload: Arc<[u8]>,
+}
+
+fn main() {}
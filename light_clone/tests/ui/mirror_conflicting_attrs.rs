@@ -0,0 +1,9 @@
+use light_clone::LightMirror;
+
+#[derive(LightMirror)]
+struct Session {
+    #[light_mirror(keep, skip)]
+    user: String,
+}
+
+fn main() {}
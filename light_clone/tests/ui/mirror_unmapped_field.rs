@@ -0,0 +1,10 @@
+use light_clone::LightMirror;
+use std::cell::RefCell;
+
+#[derive(LightMirror)]
+struct Session {
+    user: String,
+    cache: RefCell<Vec<u8>>,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use light_clone::no_heavy_clone;
+use std::sync::Arc;
+
+struct Request {
+    user: Arc<str>,
+    body: String,
+    tags: Vec<Arc<str>>,
+}
+
+#[no_heavy_clone]
+fn handle(request: &Request) -> (Arc<str>, String, Vec<Arc<str>>) {
+    let user = request.user.clone();
+    let body = request.body.clone();
+    (user, body, request.tags.clone())
+}
+
+#[no_heavy_clone]
+struct NotSupported;
+
+fn main() {}
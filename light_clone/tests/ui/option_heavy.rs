@@ -0,0 +1,7 @@
+use light_clone::LightClone;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    assert_light_clone::<Option<String>>();
+}
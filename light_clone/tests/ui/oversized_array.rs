@@ -0,0 +1,9 @@
+use light_clone::LightClone;
+
+// A large array is copied byte by byte, so it isn't a light clone
+#[derive(Clone, LightClone)]
+struct Frame {
+    pixels: [u8; 4096],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use light_clone::LightClone;
+
+// Each field fits the budget, but together they don't
+#[derive(Clone, Copy, LightClone)]
+struct Matrix {
+    rows: [[f64; 4]; 4],
+    inverse: [[f64; 4]; 4],
+    transpose: [[f64; 4]; 4],
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use light_clone::LightClone;
+
+// A where clause doesn't defer the size check to where the type is light-cloned
+#[derive(Clone, Copy, LightClone)]
+struct Matrix
+where
+    f64: Copy,
+{
+    rows: [[f64; 4]; 4],
+    inverse: [[f64; 4]; 4],
+    transpose: [[f64; 4]; 4],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use light_clone::LightClone;
+use std::path::PathBuf;
+
+#[derive(Clone, LightClone)]
+struct WatchedFile {
+    path: PathBuf,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    // The error type is cloned too, so it must be light as well
+    assert_light_clone::<Result<Arc<str>, String>>();
+    assert_light_clone::<Result<u32, Vec<u8>>>();
+}
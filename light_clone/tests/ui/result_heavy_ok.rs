@@ -0,0 +1,8 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    assert_light_clone::<Result<String, Arc<str>>>();
+}
@@ -0,0 +1,9 @@
+use light_clone::{LightClone, LightCloneSync};
+use std::cell::Cell;
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Counter {
+    hits: Cell<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use light_clone::{LightClone, LightCloneSync};
+use std::rc::Rc;
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Tagged<T> {
+    value: T,
+}
+
+fn assert_light_clone_sync<T: LightCloneSync>() {}
+
+fn main() {
+    assert_light_clone_sync::<Tagged<Rc<str>>>();
+}
@@ -0,0 +1,11 @@
+use light_clone::{LightClone, LightCloneSync};
+use std::rc::{self, Rc};
+
+#[derive(Clone, LightClone, LightCloneSync)]
+struct Snapshot {
+    version: u64,
+    body: Rc<str>,
+    parent: rc::Weak<str>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use light_clone::LightClone;
+use std::sync::Arc;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    // Every element of a tuple is cloned
+    assert_light_clone::<(u32, Arc<str>, Vec<u8>)>();
+}
@@ -0,0 +1,18 @@
+use light_clone::LightClone;
+
+// Unions are cloned bitwise, so LightClone requires them to be Copy
+#[derive(LightClone)]
+union MyUnion {
+    a: i32,
+    b: f32,
+}
+
+impl Clone for MyUnion {
+    fn clone(&self) -> Self {
+        MyUnion {
+            a: unsafe { self.a },
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use light_clone::LightClone;
+
+#[derive(Clone, LightClone)]
+#[light_clone(deep_clone)]
+struct Config {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use light_clone::LightClone;
+use std::mem::ManuallyDrop;
+use std::ops::Bound;
+use std::pin::Pin;
+use std::task::Poll;
+
+fn assert_light_clone<T: LightClone>() {}
+
+fn main() {
+    assert_light_clone::<Bound<String>>();
+    assert_light_clone::<Poll<Vec<u8>>>();
+    assert_light_clone::<Pin<Box<u32>>>();
+    assert_light_clone::<ManuallyDrop<String>>();
+    assert_light_clone::<[String; 2]>();
+}
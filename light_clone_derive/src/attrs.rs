@@ -0,0 +1,227 @@
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitInt, LitStr, Path, Result, Token, Type, WherePredicate};
+
+/// Options parsed from `#[light_clone(...)]` attributes on the deriving type.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// Generate a field-wise `Clone` impl that calls `light_clone()` on every field.
+    pub(crate) derive_clone: bool,
+    /// Where clause predicates that replace all inferred bounds, per impl.
+    pub(crate) bound: Bound,
+    /// Path to the `light_clone` crate, for crates that re-export it.
+    pub(crate) crate_path: Option<Path>,
+    /// Size budget in bytes that replaces `light_clone::MAX_COPY_SIZE` for this type.
+    pub(crate) max_size: Option<usize>,
+}
+
+impl ContainerAttrs {
+    /// Parses all `#[light_clone(...)]` attributes, rejecting unknown options.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = ContainerAttrs::default();
+
+        for attr in light_clone_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("derive_clone") {
+                    result.derive_clone = true;
+                    Ok(())
+                } else if meta.path.is_ident("bound") {
+                    result.bound.parse(&meta)
+                } else if meta.path.is_ident("crate") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.crate_path = Some(lit.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("max_size") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    result.max_size = Some(lit.base10_parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown light_clone attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Options parsed from `#[light_clone(...)]` attributes on a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// Where clause predicates that replace the bound inferred from the field type, per
+    /// impl.
+    pub(crate) bound: Bound,
+}
+
+impl FieldAttrs {
+    /// Parses all `#[light_clone(...)]` attributes on a field, rejecting unknown options.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = FieldAttrs::default();
+
+        for attr in light_clone_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    result.bound.parse(&meta)
+                } else {
+                    Err(meta.error("unknown light_clone field attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// The impl a `bound` override applies to.
+#[derive(Clone, Copy)]
+pub(crate) enum BoundTarget {
+    /// The `LightClone` impl, and the `LightCopy` impl of `#[derive(LightCopy)]`.
+    LightClone,
+    /// The `LightCloneSync` impl.
+    Sync,
+}
+
+/// Bound overrides from `bound = "..."`, which applies to every impl, or from
+/// `bound(light_clone = "...", sync = "...")`, which sets them one impl at a time.
+#[derive(Default)]
+pub(crate) struct Bound {
+    light_clone: Option<Vec<WherePredicate>>,
+    sync: Option<Vec<WherePredicate>>,
+}
+
+impl Bound {
+    /// Returns the override for `target`, if any.
+    pub(crate) fn get(&self, target: BoundTarget) -> Option<&[WherePredicate]> {
+        match target {
+            BoundTarget::LightClone => self.light_clone.as_deref(),
+            BoundTarget::Sync => self.sync.as_deref(),
+        }
+    }
+
+    fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+        if meta.input.peek(Token![=]) {
+            let predicates = parse_bound(meta)?;
+            self.light_clone = Some(predicates.clone());
+            self.sync = Some(predicates);
+            return Ok(());
+        }
+
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident("light_clone") {
+                self.light_clone = Some(parse_bound(&nested)?);
+                Ok(())
+            } else if nested.path.is_ident("sync") {
+                self.sync = Some(parse_bound(&nested)?);
+                Ok(())
+            } else {
+                Err(nested.error("expected `light_clone` or `sync`"))
+            }
+        })
+    }
+}
+
+/// Options parsed from `#[light_mirror(...)]` attributes on the deriving type.
+#[derive(Default)]
+pub(crate) struct MirrorAttrs {
+    /// Name of the generated twin, `{Name}Light` by default.
+    pub(crate) name: Option<Ident>,
+    /// Extra traits derived on the twin besides `Clone` and `LightClone`.
+    pub(crate) derives: Vec<Path>,
+    /// Path to the `light_clone` crate, for crates that re-export it.
+    pub(crate) crate_path: Option<Path>,
+}
+
+impl MirrorAttrs {
+    /// Parses all `#[light_mirror(...)]` attributes, rejecting unknown options.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = MirrorAttrs::default();
+
+        for attr in light_mirror_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.name = Some(lit.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("derive") {
+                    meta.parse_nested_meta(|derive| {
+                        result.derives.push(derive.path);
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("crate") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.crate_path = Some(lit.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown light_mirror attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// How a field is carried over to the light twin, from `#[light_mirror(...)]`.
+#[derive(Default)]
+pub(crate) enum MirrorField {
+    /// Mapped to `<T as IntoLight>::Light` and back with `FromLight`.
+    #[default]
+    Convert,
+    /// Kept as is, for fields that are already `LightClone`.
+    Keep,
+    /// Mapped to the given type with `Into` in both directions.
+    Into(Box<Type>),
+    /// Left out of the twin and restored with `Default`.
+    Skip,
+}
+
+impl MirrorField {
+    /// Parses all `#[light_mirror(...)]` attributes on a field, rejecting unknown or
+    /// conflicting options.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = None;
+
+        for attr in light_mirror_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let mapping = if meta.path.is_ident("keep") {
+                    MirrorField::Keep
+                } else if meta.path.is_ident("into") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    MirrorField::Into(Box::new(lit.parse()?))
+                } else if meta.path.is_ident("skip") {
+                    MirrorField::Skip
+                } else {
+                    return Err(meta.error("unknown light_mirror field attribute"));
+                };
+                if result.is_some() {
+                    return Err(meta.error("only one of `keep`, `into` and `skip` is allowed"));
+                }
+                result = Some(mapping);
+                Ok(())
+            })?;
+        }
+
+        Ok(result.unwrap_or_default())
+    }
+}
+
+fn light_clone_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("light_clone"))
+}
+
+fn light_mirror_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("light_mirror"))
+}
+
+/// Parses `bound = "T: Trait, U: Other"` into where clause predicates.
+///
+/// An empty string is allowed and means "no bounds".
+fn parse_bound(meta: &ParseNestedMeta) -> Result<Vec<WherePredicate>> {
+    let lit: LitStr = meta.value()?.parse()?;
+    let predicates = lit.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+    Ok(predicates.into_iter().collect())
+}
@@ -0,0 +1,65 @@
+use crate::attrs::{BoundTarget, ContainerAttrs, FieldAttrs};
+use crate::{
+    build_eager_cost, build_size_check, build_where_clause, collect_fields, crate_path,
+    diagnostics, generics, infer_bounds,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+/// Expands `#[derive(LightCopy)]` into both the `LightClone` and `LightCopy` impls.
+///
+/// Field types that depend on a generic parameter become `LightCopy` bounds, and
+/// concrete field types are asserted one by one, spanned on the field. The clone is a
+/// bitwise copy, so `COST` is always `CloneCost::COPY`, subject to the size budget.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::from_attrs(&input.attrs)?;
+    let krate = crate_path::resolve(attrs.crate_path.as_ref());
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let params = generics::type_and_const_params(&input.generics);
+
+    let bound_trait = format_ident!("LightCopy");
+    let mut predicates =
+        infer_bounds(input, &attrs, &krate, &bound_trait, BoundTarget::LightClone)?;
+    if !params.is_empty() {
+        // Field bounds alone don't make `#[derive(Clone, Copy)]` apply to every `T`
+        predicates.push(syn::parse_quote!(Self: ::core::marker::Copy));
+    }
+
+    let mut assertions = Vec::new();
+    for field in collect_fields(&input.data) {
+        let field_attrs = FieldAttrs::from_attrs(&field.attrs)?;
+        let bound = field_attrs.bound.get(BoundTarget::LightClone);
+        if bound.is_none() && !generics::mentions_params(&field.ty, &params) {
+            assertions.push(diagnostics::copy_field_assertion(&field.ty, &krate));
+        }
+    }
+
+    let cost = build_size_check(input, &attrs, &krate, quote! { #krate::CloneCost::COPY });
+    let (cost, cost_check) = build_eager_cost(input, &krate, cost);
+    let where_clause = build_where_clause(&predicates);
+
+    let clone_impl = if attrs.derive_clone {
+        quote! {
+            impl #impl_generics ::core::clone::Clone for #name #ty_generics #where_clause {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #(#assertions)*
+        #cost_check
+        impl #impl_generics #krate::LightClone for #name #ty_generics #where_clause {
+            const COST: #krate::CloneCost = #cost;
+        }
+        impl #impl_generics #krate::LightCopy for #name #ty_generics #where_clause {}
+        #clone_impl
+    })
+}
@@ -0,0 +1,40 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::ToTokens;
+use syn::{Ident, Path};
+
+/// Resolves the path generated code uses to reach the `light_clone` crate.
+///
+/// An explicit `#[light_clone(crate = "...")]` path wins. Otherwise the caller's
+/// `Cargo.toml` is consulted so a renamed dependency keeps working, falling back to
+/// `::light_clone` when it can't be found (e.g. when used through a re-export).
+pub(crate) fn resolve(explicit: Option<&Path>) -> Path {
+    if let Some(path) = explicit {
+        return path.clone();
+    }
+
+    let name = match crate_name("light_clone") {
+        Ok(FoundCrate::Name(name)) => name,
+        // `light_clone` declares `extern crate self as light_clone`, so its own
+        // tests and doctests can use the absolute path too
+        Ok(FoundCrate::Itself) | Err(_) => "light_clone".to_string(),
+    };
+    let ident = Ident::new(&name, Span::call_site());
+    syn::parse_quote!(::#ident)
+}
+
+/// Re-spans the crate path so it blends into `quote_spanned!` output.
+///
+/// Without this, errors on tokens mixing the path and a field type point at the
+/// derive instead of the field.
+pub(crate) fn respan(krate: &Path, span: Span) -> Path {
+    let tokens: TokenStream = krate
+        .to_token_stream()
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect::<TokenStream>();
+    syn::parse2(tokens).expect("re-spanned path is still a path")
+}
@@ -0,0 +1,127 @@
+use crate::crate_path;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Ident, Path, Type};
+
+/// Builds a `LightClone` assertion for a concrete field type, spanned on the type.
+///
+/// The assertion is a const expression evaluating to the field's `CloneCost`.
+/// Well-known heavy types are routed through a hint trait in `light_clone::__private`
+/// so the error suggests a light replacement.
+pub(crate) fn field_assertion(ty: &Type, krate: &Path) -> TokenStream2 {
+    let assert_fn = format_ident!("{}", assertion_fn_name(ty));
+    let krate = crate_path::respan(krate, ty.span());
+    quote_spanned! {ty.span()=>
+        #krate::__private::#assert_fn::<#ty>()
+    }
+}
+
+/// Builds a `LightCloneSync` assertion for a concrete field type, spanned on the type.
+///
+/// Single-threaded types that are `LightClone` but not `LightCloneSync` (`Rc`, `Cell`,
+/// `im_rc` and `Rc`-based rpds collections) get a hint pointing at their thread-safe
+/// replacement.
+pub(crate) fn sync_field_assertion(ty: &Type, krate: &Path) -> TokenStream2 {
+    let assert_fn = format_ident!("{}", sync_assertion_fn_name(ty));
+    let krate = crate_path::respan(krate, ty.span());
+    quote_spanned! {ty.span()=>
+        const _: () = #krate::__private::#assert_fn::<#ty>();
+    }
+}
+
+/// Builds a `LightCopy` assertion for a concrete field type, spanned on the type.
+pub(crate) fn copy_field_assertion(ty: &Type, krate: &Path) -> TokenStream2 {
+    let krate = crate_path::respan(krate, ty.span());
+    quote_spanned! {ty.span()=>
+        const _: () = #krate::__private::assert_light_copy::<#ty>();
+    }
+}
+
+/// Returns a light replacement for field types that are known to deep-copy on clone.
+///
+/// Recognizes the same syntactic patterns as the derive's hint traits, e.g. `String` or
+/// `Vec<T>`, so `#[light_clone::enforce]` can report them before the derive runs.
+pub(crate) fn heavy_type_replacement(ty: &Type) -> Option<&'static str> {
+    match assertion_fn_name(ty) {
+        "assert_string_field" => Some("`LightStr` or `Arc<str>`"),
+        "assert_path_field" => Some("`LightPath` or `LightOsStr`"),
+        "assert_vec_field" => Some("`Arc<[T]>` or `im::Vector<T>`"),
+        "assert_map_field" => Some("`im::HashMap<K, V>` or `im::OrdMap<K, V>`"),
+        "assert_set_field" => Some("`im::HashSet<T>` or `im::OrdSet<T>`"),
+        "assert_box_field" => Some("`Arc<T>`"),
+        _ => None,
+    }
+}
+
+/// Builds the assertion that a union deriving `LightClone` is `Copy`, evaluating to
+/// its `CloneCost`.
+pub(crate) fn union_assertion(name: &Ident, krate: &Path) -> TokenStream2 {
+    let krate = crate_path::respan(krate, name.span());
+    quote_spanned! {name.span()=>
+        #krate::__private::assert_copy_union::<Self>()
+    }
+}
+
+/// Picks the assertion function for a field type based on its path.
+fn assertion_fn_name(ty: &Type) -> &'static str {
+    let Type::Path(type_path) = ty else {
+        return "assert_light_clone";
+    };
+    if type_path.qself.is_some() {
+        return "assert_light_clone";
+    }
+
+    let path = &type_path.path;
+    let first = path.segments.first().map(|s| s.ident.to_string());
+    let last = path.segments.last().map(|s| s.ident.to_string());
+
+    match first.as_deref() {
+        Some("im") if path.segments.len() > 1 => return "assert_im_field",
+        Some("im_rc") if path.segments.len() > 1 => return "assert_im_rc_field",
+        Some("imbl") if path.segments.len() > 1 => return "assert_imbl_field",
+        Some("rpds") if path.segments.len() > 1 => return "assert_rpds_field",
+        _ => {}
+    }
+
+    match last.as_deref() {
+        Some("String") => "assert_string_field",
+        Some("PathBuf" | "OsString") => "assert_path_field",
+        Some("Vec" | "VecDeque") => "assert_vec_field",
+        Some("HashMap" | "BTreeMap") => "assert_map_field",
+        Some("HashSet" | "BTreeSet") => "assert_set_field",
+        Some("Box") => "assert_box_field",
+        _ => "assert_light_clone",
+    }
+}
+
+/// Picks the `LightCloneSync` assertion function for a field type based on its path.
+fn sync_assertion_fn_name(ty: &Type) -> &'static str {
+    let Type::Path(type_path) = ty else {
+        return "assert_light_clone_sync";
+    };
+    if type_path.qself.is_some() {
+        return "assert_light_clone_sync";
+    }
+
+    let path = &type_path.path;
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let first = segments.first().map(String::as_str);
+    let last = segments.last().map(String::as_str);
+
+    match first {
+        Some("im_rc") if segments.len() > 1 => return "assert_sync_im_rc_field",
+        Some("rpds") if segments.len() > 1 && !last.is_some_and(|l| l.ends_with("Sync")) => {
+            return "assert_sync_rpds_field"
+        }
+        _ => {}
+    }
+
+    match last {
+        Some("Rc") => "assert_sync_rc_field",
+        // A bare `Weak` may be `sync::Weak`, so only flag it under an `rc` path
+        Some("Weak") if segments.iter().any(|s| s == "rc") => "assert_sync_rc_field",
+        Some("Cell" | "RefCell") => "assert_sync_cell_field",
+        _ => "assert_light_clone_sync",
+    }
+}
@@ -0,0 +1,251 @@
+use crate::attrs::ContainerAttrs;
+use crate::{collect_fields, crate_path, diagnostics};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, DeriveInput, Item, ItemMod, LitStr, Path, Token};
+
+/// Expands `#[light_clone::enforce]` on an inline module.
+///
+/// Every struct and enum deriving `Clone` gets `#[derive(LightClone)]` unless it already
+/// derives `LightClone` or `LightCopy`, or is marked `#[light_clone(exempt)]`. Fields of
+/// syntactically heavy types (`String`, `Vec<T>`, ...) are reported together in one
+/// error instead; anything else is caught by the derive's own field assertions.
+pub(crate) fn expand(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut crate_lit: Option<LitStr> = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            crate_lit = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown enforce argument"))
+        }
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    let mut module = match syn::parse2::<Item>(item)? {
+        Item::Mod(module) if module.content.is_some() => module,
+        Item::Mod(module) => return Err(syn::Error::new_spanned(
+            module,
+            "#[light_clone::enforce] needs an inline module (`mod name { ... }`), not `mod name;`",
+        )),
+        other => {
+            return Err(syn::Error::new_spanned(
+                other,
+                "#[light_clone::enforce] can only be applied to inline modules",
+            ))
+        }
+    };
+
+    let explicit = match &crate_lit {
+        Some(lit) => Some(lit.parse::<Path>()?),
+        None => None,
+    };
+    let mut enforcer = Enforcer {
+        krate: crate_path::resolve(explicit.as_ref()),
+        crate_lit,
+        offenders: Vec::new(),
+    };
+    enforcer.visit_module(&mut module)?;
+
+    if enforcer.offenders.is_empty() {
+        return Ok(module.into_token_stream());
+    }
+
+    let summary = enforcer
+        .offenders
+        .iter()
+        .map(|offender| format!("`{}` ({})", offender.ty_name, offender.field))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut error = syn::Error::new_spanned(
+        &module.ident,
+        format!(
+            "types in `mod {}` must be `LightClone`, but these fields deep-copy on clone: {}",
+            module.ident, summary
+        ),
+    );
+    for offender in &enforcer.offenders {
+        error.combine(syn::Error::new_spanned(
+            &offender.field_ty,
+            format!(
+                "`{}` deep-copies on clone; use {} instead, or mark `{}` `#[light_clone(exempt)]`",
+                type_string(&offender.field_ty),
+                offender.replacement,
+                offender.ty_name
+            ),
+        ));
+    }
+
+    // Keep the module so the rest of the crate still resolves its items
+    let error = error.to_compile_error();
+    Ok(quote! {
+        #error
+        #module
+    })
+}
+
+struct Enforcer {
+    krate: Path,
+    crate_lit: Option<LitStr>,
+    offenders: Vec<Offender>,
+}
+
+/// A field of a syntactically heavy type in a type that must be `LightClone`.
+struct Offender {
+    ty_name: String,
+    field: String,
+    field_ty: syn::Type,
+    replacement: &'static str,
+}
+
+impl Enforcer {
+    fn visit_module(&mut self, module: &mut ItemMod) -> syn::Result<()> {
+        let Some((_, items)) = &mut module.content else {
+            return Ok(());
+        };
+
+        for item in items {
+            match item {
+                Item::Struct(item) => {
+                    let input = DeriveInput::from(item.clone());
+                    self.enforce(&mut item.attrs, &input)?;
+                }
+                Item::Enum(item) => {
+                    let input = DeriveInput::from(item.clone());
+                    self.enforce(&mut item.attrs, &input)?;
+                }
+                Item::Mod(nested) => self.visit_module(nested)?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the `LightClone` derive to one type, or records its heavy fields.
+    fn enforce(&mut self, attrs: &mut Vec<Attribute>, input: &DeriveInput) -> syn::Result<()> {
+        if take_exempt_attr(attrs)? {
+            return Ok(());
+        }
+
+        let derives = derived_traits(attrs)?;
+        let Some(clone_index) = derives.clone_attr else {
+            return Ok(());
+        };
+        if derives.light_clone {
+            return Ok(());
+        }
+
+        let before = self.offenders.len();
+        for field in collect_fields(&input.data) {
+            if let Some(replacement) = diagnostics::heavy_type_replacement(&field.ty) {
+                let field_name = match &field.ident {
+                    Some(ident) => format!("field `{}: {}`", ident, type_string(&field.ty)),
+                    None => format!("field of type `{}`", type_string(&field.ty)),
+                };
+                self.offenders.push(Offender {
+                    ty_name: input.ident.to_string(),
+                    field: field_name,
+                    field_ty: field.ty.clone(),
+                    replacement,
+                });
+            }
+        }
+        if self.offenders.len() != before {
+            return Ok(());
+        }
+
+        // Right after the `Clone` derive, so the `light_clone` helper attributes that
+        // usually follow the derives are in scope
+        let krate = &self.krate;
+        let mut added: Vec<Attribute> = vec![syn::parse_quote!(#[derive(#krate::LightClone)])];
+        if let Some(lit) = &self.crate_lit {
+            if ContainerAttrs::from_attrs(attrs)?.crate_path.is_none() {
+                added.push(syn::parse_quote!(#[light_clone(crate = #lit)]));
+            }
+        }
+        attrs.splice(clone_index + 1..clone_index + 1, added);
+
+        Ok(())
+    }
+}
+
+/// What a type's `#[derive(...)]` attributes already cover.
+struct Derives {
+    /// Index of the attribute deriving `Clone`, if any.
+    clone_attr: Option<usize>,
+    /// Whether `LightClone` or `LightCopy` is already derived.
+    light_clone: bool,
+}
+
+fn derived_traits(attrs: &[Attribute]) -> syn::Result<Derives> {
+    let mut derives = Derives {
+        clone_attr: None,
+        light_clone: false,
+    };
+
+    for (index, attr) in attrs.iter().enumerate() {
+        if !attr.path().is_ident("derive") {
+            continue;
+        }
+        let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        for path in paths {
+            let Some(last) = path.segments.last() else {
+                continue;
+            };
+            if last.ident == "Clone" {
+                derives.clone_attr.get_or_insert(index);
+            } else if last.ident == "LightClone" || last.ident == "LightCopy" {
+                derives.light_clone = true;
+            }
+        }
+    }
+
+    Ok(derives)
+}
+
+/// Removes `#[light_clone(exempt)]`, returning whether it was present.
+///
+/// The attribute is removed rather than left for the derive, since an exempt type
+/// usually has no `light_clone` derive to declare the helper attribute.
+fn take_exempt_attr(attrs: &mut Vec<Attribute>) -> syn::Result<bool> {
+    let mut exempt = false;
+    let mut result = Ok(());
+
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("light_clone") {
+            return true;
+        }
+        let mut is_exempt = false;
+        let parsed = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("exempt") {
+                is_exempt = true;
+            } else if meta.input.peek(Token![=]) {
+                // Other options belong to the derive; skip their values
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        });
+        if let Err(err) = parsed {
+            result = Err(err);
+        }
+        if is_exempt {
+            exempt = true;
+        }
+        !is_exempt
+    });
+
+    result.map(|()| exempt)
+}
+
+/// Renders a type for an error message, without the spaces token printing adds.
+fn type_string(ty: &syn::Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
@@ -0,0 +1,50 @@
+use proc_macro2::Ident;
+use syn::visit::{self, Visit};
+use syn::{GenericParam, Generics, Type};
+
+/// Returns the names of the type and const parameters declared in `generics`.
+pub(crate) fn type_and_const_params(generics: &Generics) -> Vec<Ident> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(type_param.ident.clone()),
+            GenericParam::Const(const_param) => Some(const_param.ident.clone()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect()
+}
+
+/// Returns true if `ty` refers to any of `params`.
+///
+/// Types containing macros are treated as generic since their expansion is unknown.
+pub(crate) fn mentions_params(ty: &Type, params: &[Ident]) -> bool {
+    struct Finder<'a> {
+        params: &'a [Ident],
+        found: bool,
+    }
+
+    impl<'ast> Visit<'ast> for Finder<'_> {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if path.leading_colon.is_none() {
+                if let Some(first) = path.segments.first() {
+                    if self.params.contains(&first.ident) {
+                        self.found = true;
+                    }
+                }
+            }
+            visit::visit_path(self, path);
+        }
+
+        fn visit_type_macro(&mut self, _: &'ast syn::TypeMacro) {
+            self.found = true;
+        }
+    }
+
+    let mut finder = Finder {
+        params,
+        found: false,
+    };
+    finder.visit_type(ty);
+    finder.found
+}
@@ -0,0 +1,150 @@
+use crate::crate_path;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_quote, Attribute, Expr, ImplItemFn, Item, ItemFn, LitStr, Path, Stmt};
+
+/// Attribute that exempts a statement, expression or function from `#[no_heavy_clone]`.
+const ALLOW_ATTR: &str = "allow_heavy_clone";
+
+/// Expands `#[no_heavy_clone]` on a function, impl block or inline module.
+///
+/// Every `.clone()` call without arguments is rewritten to a method that requires the
+/// receiver to be `LightClone`, spanned on the original call so an error points at it.
+/// The rewritten method resolves through the same auto-ref/auto-deref steps as
+/// `Clone::clone`, so it clones exactly the value `.clone()` would have cloned.
+pub(crate) fn expand(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut crate_path = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            let lit: LitStr = meta.value()?.parse()?;
+            crate_path = Some(lit.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown no_heavy_clone argument"))
+        }
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+
+    let mut item: Item = syn::parse2(item)?;
+    match &item {
+        Item::Fn(_) | Item::Impl(_) => {}
+        Item::Mod(module) if module.content.is_some() => {}
+        other => return Err(syn::Error::new_spanned(
+            other,
+            "#[no_heavy_clone] can only be applied to functions, impl blocks and inline modules",
+        )),
+    }
+
+    let mut rewriter = CloneRewriter {
+        krate: crate_path::resolve(crate_path.as_ref()),
+    };
+    rewriter.visit_item_mut(&mut item);
+    Ok(item.into_token_stream())
+}
+
+struct CloneRewriter {
+    krate: Path,
+}
+
+/// Removes `#[allow_heavy_clone]` from `attrs`, returning whether it was present.
+fn take_allow_attr(attrs: &mut Vec<Attribute>) -> bool {
+    let before = attrs.len();
+    attrs.retain(|attr| !attr.path().is_ident(ALLOW_ATTR));
+    attrs.len() != before
+}
+
+/// Strips any `#[allow_heavy_clone]` attributes left in an exempted subtree.
+struct StripAllowAttrs;
+
+impl VisitMut for StripAllowAttrs {
+    fn visit_attributes_mut(&mut self, attrs: &mut Vec<Attribute>) {
+        take_allow_attr(attrs);
+    }
+}
+
+impl VisitMut for CloneRewriter {
+    fn visit_item_fn_mut(&mut self, item: &mut ItemFn) {
+        if take_allow_attr(&mut item.attrs) {
+            StripAllowAttrs.visit_item_fn_mut(item);
+        } else {
+            visit_mut::visit_item_fn_mut(self, item);
+        }
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, item: &mut ImplItemFn) {
+        if take_allow_attr(&mut item.attrs) {
+            StripAllowAttrs.visit_impl_item_fn_mut(item);
+        } else {
+            visit_mut::visit_impl_item_fn_mut(self, item);
+        }
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        let attrs = match stmt {
+            Stmt::Local(local) => &mut local.attrs,
+            Stmt::Macro(mac) => &mut mac.attrs,
+            Stmt::Item(_) | Stmt::Expr(..) => return visit_mut::visit_stmt_mut(self, stmt),
+        };
+        if take_allow_attr(attrs) {
+            StripAllowAttrs.visit_stmt_mut(stmt);
+        } else {
+            visit_mut::visit_stmt_mut(self, stmt);
+        }
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Some(attrs) = expr_attrs_mut(expr) {
+            if take_allow_attr(attrs) {
+                StripAllowAttrs.visit_expr_mut(expr);
+                return;
+            }
+        }
+
+        visit_mut::visit_expr_mut(self, expr);
+
+        let Expr::MethodCall(call) = expr else {
+            return;
+        };
+        if call.method != "clone" || !call.args.is_empty() || call.turbofish.is_some() {
+            return;
+        }
+
+        let attrs = &call.attrs;
+        let receiver = &call.receiver;
+        let span = call.method.span();
+        let krate = crate_path::respan(&self.krate, span);
+        let method = quote_spanned! {span=> __light_clone_checked };
+        let checked = quote! {{
+            #[allow(unused_imports)]
+            use #krate::__private::CheckedClone as _;
+            (#receiver).#method()
+        }};
+        let checked: Expr = parse_quote!(#checked);
+        *expr = if attrs.is_empty() {
+            checked
+        } else {
+            parse_quote!(#(#attrs)* #checked)
+        };
+    }
+}
+
+/// Returns the outer attributes of expressions that can carry them in statement position.
+fn expr_attrs_mut(expr: &mut Expr) -> Option<&mut Vec<Attribute>> {
+    match expr {
+        Expr::Array(e) => Some(&mut e.attrs),
+        Expr::Assign(e) => Some(&mut e.attrs),
+        Expr::Block(e) => Some(&mut e.attrs),
+        Expr::Call(e) => Some(&mut e.attrs),
+        Expr::Closure(e) => Some(&mut e.attrs),
+        Expr::If(e) => Some(&mut e.attrs),
+        Expr::Macro(e) => Some(&mut e.attrs),
+        Expr::Match(e) => Some(&mut e.attrs),
+        Expr::MethodCall(e) => Some(&mut e.attrs),
+        Expr::Path(e) => Some(&mut e.attrs),
+        Expr::Return(e) => Some(&mut e.attrs),
+        Expr::Struct(e) => Some(&mut e.attrs),
+        Expr::Tuple(e) => Some(&mut e.attrs),
+        _ => None,
+    }
+}
@@ -1,11 +1,25 @@
+mod attrs;
+mod copy;
+mod crate_path;
+mod diagnostics;
+mod enforce;
+mod generics;
+mod heavy_clone;
+mod mirror;
+mod sync;
+
+use attrs::{BoundTarget, ContainerAttrs, FieldAttrs};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, GenericParam, WhereClause, WherePredicate};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Ident, Path, WherePredicate};
 
 /// Derive macro for `LightClone` trait.
 ///
-/// This macro generates a `LightClone` implementation for structs and enums.
+/// This macro generates a `LightClone` implementation for structs, enums and `Copy`
+/// unions.
 /// The generated impl uses the default `light_clone()` method (which calls `clone()`),
 /// but adds `LightClone` bounds for all field types to ensure compile-time enforcement
 /// that all fields are O(1) to clone.
@@ -22,83 +36,364 @@ use syn::{parse_macro_input, Data, DeriveInput, GenericParam, WhereClause, Where
 /// }
 /// ```
 ///
-/// **Important:** You must also derive or implement `Clone` separately. The derive macro
-/// no longer generates a `Clone` impl - it only generates `LightClone`.
+/// **Important:** You must also derive or implement `Clone` separately, unless you opt
+/// into [`#[light_clone(derive_clone)]`](#generating-clone) to have the macro generate it.
 ///
 /// # How it works
 ///
-/// The macro generates an impl with where clause bounds that require all field types
-/// to implement `LightClone`. This provides compile-time enforcement that all fields
-/// are cheap to clone, while the actual cloning is delegated to the `Clone` impl.
+/// The macro requires every field type to implement `LightClone`. This provides
+/// compile-time enforcement that all fields are cheap to clone, while the actual
+/// cloning is delegated to the `Clone` impl.
+///
+/// Concrete field types are checked with a separate assertion per field, so an error
+/// points at the offending field. Field types that depend on generic parameters become
+/// where clause bounds on the generated impl.
+///
+/// For generic types, only the field types that mention a generic parameter are bounded,
+/// so `struct Handle<T> { inner: Arc<T> }` is `LightClone` for any `T` that makes it
+/// `Clone`. Fields like `PhantomData<T>`, `Arc<T>` or `&'a T` add no bound on `T`.
+///
+/// # Overriding bounds
+///
+/// Like serde, `#[light_clone(bound = "...")]` replaces the inferred bounds, either for
+/// the whole type or for a single field. An empty string removes them entirely:
+///
+/// ```ignore
+/// #[derive(Clone, LightClone)]
+/// #[light_clone(bound = "T: LightClone")]
+/// pub struct Public<T> {
+///     inner: Private<T>,
+/// }
+/// ```
+///
+/// `bound = "..."` also applies to `#[derive(LightCloneSync)]`. To override one impl
+/// at a time, use `bound(light_clone = "...", sync = "...")`; the `light_clone` key also
+/// covers `#[derive(LightCopy)]`.
+///
+/// # Unions
+///
+/// Unions are cloned bitwise, so the derive accepts them only when they are `Copy`,
+/// which is enforced with a compile-time assertion:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, LightClone)]
+/// #[repr(C)]
+/// union PacketHeader {
+///     raw: [u8; 8],
+///     value: u64,
+/// }
+/// ```
+///
+/// # Size budget
+///
+/// A bitwise copy still copies every byte, so types in the `Copy` tier must fit in
+/// `light_clone::MAX_COPY_SIZE` (256 bytes unless configured), checked when `COST` is
+/// evaluated. `#[light_clone(max_size = N)]` holds the type to `N` bytes instead,
+/// whatever its tier:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, LightClone)]
+/// #[light_clone(max_size = 512)]
+/// struct Transform {
+///     matrix: [[f64; 4]; 4],
+///     inverse: [[f64; 4]; 4],
+/// }
+/// ```
+///
+/// # Crate path
+///
+/// Generated code finds `light_clone` through your `Cargo.toml`, so renaming the
+/// dependency works out of the box. If you reach it through a re-export instead, point
+/// the derive at it with `#[light_clone(crate = "...")]`:
+///
+/// ```ignore
+/// use my_prelude::light_clone::LightClone;
+///
+/// #[derive(Clone, LightClone)]
+/// #[light_clone(crate = "my_prelude::light_clone")]
+/// struct Config {
+///     port: u16,
+/// }
+/// ```
+///
+/// # Generating `Clone`
 ///
-/// For generic types, the macro adds `LightClone` bounds to ensure the generic
-/// parameters also satisfy the O(1) clone requirement.
-#[proc_macro_derive(LightClone)]
+/// With `#[light_clone(derive_clone)]`, the macro also generates the `Clone` impl. The
+/// generated `clone()` calls `LightClone::light_clone` on every field, so the O(1)
+/// guarantee covers the clone body as well as the field types. Don't combine this with
+/// `#[derive(Clone)]`:
+///
+/// ```ignore
+/// use light_clone::LightClone;
+///
+/// #[derive(LightClone)]
+/// #[light_clone(derive_clone)]
+/// struct Person {
+///     id: i64,
+///     name: Arc<str>,
+/// }
+/// ```
+#[proc_macro_derive(LightClone, attributes(light_clone))]
 pub fn derive_light_clone(input: TokenStream) -> TokenStream {
     derive_light_clone_impl(input)
 }
 
-/// Collects all field types from a struct or enum for generating where clause bounds.
-fn collect_field_types(data: &Data) -> Vec<syn::Type> {
-    let mut types = Vec::new();
+/// Derive macro for the `LightCloneSync` trait.
+///
+/// Generates a `LightCloneSync` impl that requires every field to be `LightCloneSync`,
+/// i.e. a `Copy` or atomically refcounted light clone. Use it next to
+/// `#[derive(Clone, LightClone)]` on types that are shared across threads:
+///
+/// ```ignore
+/// use light_clone::{LightClone, LightCloneSync};
+///
+/// #[derive(Clone, LightClone, LightCloneSync)]
+/// struct Snapshot {
+///     version: u64,
+///     body: Arc<str>,
+/// }
+/// ```
+///
+/// A field such as `Rc<str>`, `Cell<u32>` or `rpds::Vector<T>` fails to compile with a
+/// hint naming its thread-safe replacement. Field types that depend on a generic
+/// parameter become `LightCloneSync` bounds instead. The `crate = "..."` and
+/// `bound = "..."` options of `#[light_clone(...)]` apply to this derive, and
+/// `bound(sync = "...")` replaces the `LightCloneSync` bounds without touching the
+/// `LightClone` impl:
+///
+/// ```ignore
+/// #[derive(Clone, LightClone, LightCloneSync)]
+/// #[light_clone(bound(sync = "T: LightCloneSync"))]
+/// pub struct Public<T> {
+///     inner: Private<T>,
+/// }
+/// ```
+#[proc_macro_derive(LightCloneSync, attributes(light_clone))]
+pub fn derive_light_clone_sync(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sync::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derive macro for the `LightCopy` trait.
+///
+/// Generates both the `LightCopy` and `LightClone` impls for a `Copy` type whose fields
+/// are all `LightCopy`, so its clone is a bitwise copy with no refcount traffic. Use it
+/// instead of `#[derive(LightClone)]`, next to `#[derive(Clone, Copy)]`:
+///
+/// ```ignore
+/// use light_clone::LightCopy;
+///
+/// #[derive(Clone, Copy, LightCopy)]
+/// struct Tick {
+///     price: f64,
+///     size: u32,
+/// }
+/// ```
+///
+/// A field such as `Arc<str>` or a `Copy` type without a `LightCopy` impl fails to
+/// compile, pointing at the field. The `bound`, `crate` and `max_size` options of
+/// `#[light_clone(...)]` work as for `#[derive(LightClone)]`, and `derive_clone`
+/// generates a `Clone` impl returning `*self`.
+#[proc_macro_derive(LightCopy, attributes(light_clone))]
+pub fn derive_light_copy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    copy::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derive macro generating a light twin of a type, with conversions both ways.
+///
+/// For a struct or enum `Foo`, generates `FooLight` with the same shape, where every
+/// field type `T` becomes `<T as IntoLight>::Light`: `String` becomes `LightStr`,
+/// `Vec<T>` becomes `Arc<[T::Light]>`, maps become persistent maps when a collection
+/// feature is enabled, and fields of other `LightMirror` types become their twins. The
+/// twin is `Clone` and `LightClone`, and comes with `From<Foo> for FooLight` and
+/// `From<&FooLight> for Foo`, as well as `IntoLight` and `FromLight` impls for `Foo`:
+///
+/// ```ignore
+/// use light_clone::LightMirror;
+///
+/// #[derive(LightMirror)]
+/// #[light_mirror(derive(Debug, PartialEq))]
+/// pub struct UserDto {
+///     pub name: String,
+///     pub tags: Vec<String>,
+///     pub address: AddressDto, // also `#[derive(LightMirror)]`
+/// }
+///
+/// let user: UserDtoLight = dto.into();
+/// let dto = UserDto::from(&user);
+/// ```
+///
+/// # Field mapping
+///
+/// - `#[light_mirror(keep)]` keeps a field that is already `LightClone` as is.
+/// - `#[light_mirror(into = "Type")]` stores the field as `Type`, converting with `Into`
+///   in both directions.
+/// - `#[light_mirror(skip)]` leaves the field out of the twin; converting back fills it
+///   in with `Default::default()`.
+///
+/// # Container options
+///
+/// `#[light_mirror(name = "...")]` renames the twin, `#[light_mirror(derive(...))]`
+/// derives more traits on it, and `#[light_mirror(crate = "...")]` points the generated
+/// code at a re-exported `light_clone`. The twin has the visibility and generics of the
+/// original type, and keeps its field and variant doc comments.
+#[proc_macro_derive(LightMirror, attributes(light_mirror))]
+pub fn derive_light_mirror(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    mirror::expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Attribute macro that rejects deep `.clone()` calls in a function, impl block or
+/// inline module.
+///
+/// Every `.clone()` call in the annotated item is rewritten to a call that requires the
+/// receiver to be `LightClone`, so cloning a `String`, `Vec` or any other heavy type
+/// fails to compile at that call:
+///
+/// ```ignore
+/// #[light_clone::no_heavy_clone]
+/// fn handle(request: &Request) -> Response {
+///     let user = request.user.clone(); // ok: `Arc<User>`
+///     let body = request.body.clone(); // error: `String` is not `LightClone`
+///     # todo!()
+/// }
+/// ```
+///
+/// Mark an intentional deep copy with `#[allow_heavy_clone]` on the statement, or on a
+/// nested function to exempt it entirely:
+///
+/// ```ignore
+/// #[allow_heavy_clone]
+/// let mut body = request.body.clone();
+/// ```
+///
+/// Calls inside macro invocations such as `format!` or `vec!` are not rewritten, since
+/// their arguments aren't parsed as expressions. Use `#[no_heavy_clone(crate = "...")]`
+/// if `light_clone` is only reachable through a re-export.
+#[proc_macro_attribute]
+pub fn no_heavy_clone(args: TokenStream, item: TokenStream) -> TokenStream {
+    heavy_clone::expand(args.into(), item.into())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Attribute macro that makes every `Clone` type in an inline module `LightClone`.
+///
+/// Every struct and enum in the module (and its inline submodules) that derives `Clone`
+/// gets `#[derive(LightClone)]` added, so new types can't silently be heavy to clone:
+///
+/// ```ignore
+/// #[light_clone::enforce]
+/// mod state {
+///     #[derive(Clone)]
+///     pub struct Session {
+///         pub user: Arc<str>,
+///         pub roles: im::Vector<Role>,
+///     }
+///
+///     #[derive(Clone)]
+///     #[light_clone(exempt)]
+///     pub struct Draft {
+///         pub text: String,
+///     }
+/// }
+/// ```
+///
+/// Fields of well-known heavy types such as `String`, `Vec<T>` or `HashMap<K, V>` are
+/// reported together in one error listing every offending type and field; any other
+/// field that isn't `LightClone` is reported by the derive itself. Types that already
+/// derive `LightClone` or `LightCopy` are left alone, and `#[light_clone(exempt)]` opts
+/// a type out. Use `#[light_clone::enforce(crate = "...")]` if `light_clone` is only
+/// reachable through a re-export.
+#[proc_macro_attribute]
+pub fn enforce(args: TokenStream, item: TokenStream) -> TokenStream {
+    enforce::expand(args.into(), item.into())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Collects all fields from a struct or enum, in declaration order.
+fn collect_fields(data: &Data) -> Vec<&Field> {
+    let mut fields = Vec::new();
 
     match data {
         Data::Struct(data_struct) => {
-            for field in &data_struct.fields {
-                types.push(field.ty.clone());
-            }
+            fields.extend(&data_struct.fields);
         }
         Data::Enum(data_enum) => {
             for variant in &data_enum.variants {
-                for field in &variant.fields {
-                    types.push(field.ty.clone());
-                }
+                fields.extend(&variant.fields);
             }
         }
         Data::Union(_) => {
-            // Unions are handled separately with an error
+            // Union fields are never cloned individually; the union is copied bitwise
         }
     }
 
-    types
+    fields
 }
 
-/// Builds the where clause with LightClone bounds for all field types.
-fn build_where_clause(
-    existing: Option<&WhereClause>,
-    field_types: &[syn::Type],
-    generics: &syn::Generics,
-) -> TokenStream2 {
-    // Collect existing predicates
-    let mut predicates: Vec<WherePredicate> = existing
+/// Infers the where clause predicates for the generated impls from the field types.
+///
+/// Only field types that depend on a generic parameter become where clause bounds on
+/// `bound_trait` (`LightClone` or `LightCopy`), so `Arc<T>` or `PhantomData<T>` fields
+/// don't force `T: LightClone`. Concrete field types are checked by per-field
+/// assertions instead, so they don't leak into the impl signature.
+/// `#[light_clone(bound = "...")]` replaces the inferred bounds for the whole type or
+/// for a single field; `target` picks the override that applies to this impl.
+fn infer_bounds(
+    input: &DeriveInput,
+    attrs: &ContainerAttrs,
+    krate: &Path,
+    bound_trait: &Ident,
+    target: BoundTarget,
+) -> syn::Result<Vec<WherePredicate>> {
+    let params = generics::type_and_const_params(&input.generics);
+
+    // Start from the user's own where clause
+    let mut predicates: Vec<WherePredicate> = input
+        .generics
+        .where_clause
+        .as_ref()
         .map(|w| w.predicates.iter().cloned().collect())
         .unwrap_or_default();
+    let mut inferred = Vec::new();
 
-    // Get type parameter names for adding LightClone bounds
-    let type_params: Vec<_> = generics
-        .params
-        .iter()
-        .filter_map(|param| {
-            if let GenericParam::Type(type_param) = param {
-                Some(type_param.ident.clone())
-            } else {
-                None
-            }
-        })
-        .collect();
+    for field in collect_fields(&input.data) {
+        let field_attrs = FieldAttrs::from_attrs(&field.attrs)?;
+        let ty = &field.ty;
 
-    // Add LightClone bounds for all type parameters
-    for type_param in &type_params {
-        let predicate: WherePredicate = syn::parse_quote!(#type_param: light_clone::LightClone);
-        predicates.push(predicate);
+        if let Some(bound) = field_attrs.bound.get(target) {
+            predicates.extend(bound.iter().cloned());
+        } else if generics::mentions_params(ty, &params) {
+            let krate = crate_path::respan(krate, ty.span());
+            let predicate: WherePredicate =
+                syn::parse_quote_spanned!(ty.span()=> #ty: #krate::#bound_trait);
+            inferred.push(predicate);
+        }
     }
 
-    // Add LightClone bounds for all field types
-    for ty in field_types {
-        let predicate: WherePredicate = syn::parse_quote!(#ty: light_clone::LightClone);
-        predicates.push(predicate);
+    match attrs.bound.get(target) {
+        Some(bound) => predicates.extend(bound.iter().cloned()),
+        None => predicates.extend(inferred),
     }
 
+    // Identical field types would otherwise repeat the same bound
+    let mut seen = HashSet::new();
+    predicates.retain(|predicate| seen.insert(predicate.to_token_stream().to_string()));
+
+    Ok(predicates)
+}
+
+/// Renders where clause predicates, or nothing if there are none.
+fn build_where_clause(predicates: &[WherePredicate]) -> TokenStream2 {
     if predicates.is_empty() {
         quote! {}
     } else {
@@ -106,33 +401,255 @@ fn build_where_clause(
     }
 }
 
+/// Builds the `CloneCost` of a single field.
+///
+/// Concrete field types go through a compile-time assertion spanned on the field, so a
+/// heavy field is reported where it is declared. Field types covered by a where clause
+/// bound read their cost from `LightClone::COST` directly.
+fn build_field_cost(field: &Field, params: &[Ident], krate: &Path) -> syn::Result<TokenStream2> {
+    let field_attrs = FieldAttrs::from_attrs(&field.attrs)?;
+    let ty = &field.ty;
+
+    let bound = field_attrs.bound.get(BoundTarget::LightClone);
+    if bound.is_none() && !generics::mentions_params(ty, params) {
+        return Ok(diagnostics::field_assertion(ty, krate));
+    }
+
+    let krate = crate_path::respan(krate, ty.span());
+    Ok(quote_spanned! {ty.span()=>
+        <#ty as #krate::LightClone>::COST
+    })
+}
+
+/// Builds the `LightClone::COST` expression for the deriving type.
+///
+/// Fields are cloned together, so their costs add up; only one enum variant is cloned,
+/// so a variant's cost counts only if it is the most expensive one.
+fn build_cost(input: &DeriveInput, krate: &Path) -> syn::Result<TokenStream2> {
+    let params = generics::type_and_const_params(&input.generics);
+    let fields_cost = |fields: &Fields| -> syn::Result<TokenStream2> {
+        let costs = fields
+            .iter()
+            .map(|field| build_field_cost(field, &params, krate))
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok(quote! { #krate::CloneCost::COPY #(.plus(#costs))* })
+    };
+
+    match &input.data {
+        Data::Struct(data_struct) => fields_cost(&data_struct.fields),
+        Data::Enum(data_enum) => {
+            let costs = data_enum
+                .variants
+                .iter()
+                .map(|variant| fields_cost(&variant.fields))
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok(quote! { #krate::CloneCost::COPY #(.max(#costs))* })
+        }
+        // Unions are cloned bitwise; generic unions are bounded by `Self: Copy` instead
+        Data::Union(_) if params.is_empty() => {
+            Ok(diagnostics::union_assertion(&input.ident, krate))
+        }
+        Data::Union(_) => Ok(quote! { #krate::CloneCost::COPY }),
+    }
+}
+
+/// Builds the body of a field-wise `clone()` that light-clones every field.
+fn build_clone_body(data: &Data, krate: &Path) -> TokenStream2 {
+    match data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => {
+                let clones = fields.named.iter().map(|field| {
+                    let ident = &field.ident;
+                    let krate = crate_path::respan(krate, field.ty.span());
+                    quote_spanned! {field.ty.span()=>
+                        #ident: #krate::LightClone::light_clone(&self.#ident)
+                    }
+                });
+                quote! { Self { #(#clones),* } }
+            }
+            Fields::Unnamed(fields) => {
+                let clones = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                    let index = syn::Index::from(index);
+                    let krate = crate_path::respan(krate, field.ty.span());
+                    quote_spanned! {field.ty.span()=>
+                        #krate::LightClone::light_clone(&self.#index)
+                    }
+                });
+                quote! { Self(#(#clones),*) }
+            }
+            Fields::Unit => quote! { Self },
+        },
+        Data::Enum(data_enum) if data_enum.variants.is_empty() => quote! { match *self {} },
+        Data::Enum(data_enum) => {
+            let arms = data_enum.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Named(fields) => {
+                        let names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+                        quote! {
+                            Self::#ident { #(#names),* } => Self::#ident {
+                                #(#names: #krate::LightClone::light_clone(#names)),*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(fields) => {
+                        let bindings: Vec<_> = (0..fields.unnamed.len())
+                            .map(|index| format_ident!("__field{}", index))
+                            .collect();
+                        quote! {
+                            Self::#ident(#(#bindings),*) => Self::#ident(
+                                #(#krate::LightClone::light_clone(#bindings)),*
+                            )
+                        }
+                    }
+                    Fields::Unit => quote! { Self::#ident => Self::#ident },
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        // Unions are only accepted when Copy, so a bitwise copy is the light clone
+        Data::Union(_) => quote! { *self },
+    }
+}
+
+/// Wraps the `COST` expression in a compile-time check of `size_of::<Self>()`.
+///
+/// By default bitwise copies are held to `light_clone::MAX_COPY_SIZE`. With
+/// `#[light_clone(max_size = N)]` the type is held to `N` bytes instead, whatever its tier.
+fn build_size_check(
+    input: &DeriveInput,
+    attrs: &ContainerAttrs,
+    krate: &Path,
+    cost: TokenStream2,
+) -> TokenStream2 {
+    match attrs.max_size {
+        Some(max_size) => {
+            let message = format!(
+                "`{}` is larger than its `#[light_clone(max_size = {})]`",
+                input.ident, max_size
+            );
+            quote! {{
+                ::core::assert!(::core::mem::size_of::<Self>() <= #max_size, #message);
+                #cost
+            }}
+        }
+        None => quote! { #krate::__private::check_copy_size::<Self>(#cost) },
+    }
+}
+
+/// Evaluates the `COST` expression eagerly for non-generic types.
+///
+/// Without generics the cost can be evaluated right away, so an oversized type is
+/// rejected where it is declared rather than where it is first light-cloned. It lives
+/// in an inherent const so a missing `Clone` impl isn't reported twice. Returns the
+/// expression to use for `COST` and the items performing the check.
+fn build_eager_cost(
+    input: &DeriveInput,
+    krate: &Path,
+    cost: TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    if !input.generics.params.is_empty() {
+        return (cost, quote! {});
+    }
+
+    let name = &input.ident;
+    let check = quote! {
+        impl #name {
+            #[doc(hidden)]
+            const __LIGHT_CLONE_COST: #krate::CloneCost = #cost;
+        }
+        const _: #krate::CloneCost = #name::__LIGHT_CLONE_COST;
+    };
+    (quote! { Self::__LIGHT_CLONE_COST }, check)
+}
+
 fn derive_light_clone_impl(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let attrs = match ContainerAttrs::from_attrs(&input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let name = &input.ident;
     let generics = &input.generics;
     let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
-    // Handle unions with an error
+    // Infer where clause bounds and per-field assertions from the field types
+    let krate = crate_path::resolve(attrs.crate_path.as_ref());
+    let bound_trait = format_ident!("LightClone");
+    let predicates = match infer_bounds(
+        &input,
+        &attrs,
+        &krate,
+        &bound_trait,
+        BoundTarget::LightClone,
+    ) {
+        Ok(predicates) => predicates,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let cost = match build_cost(&input, &krate) {
+        Ok(cost) => cost,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let cost = build_size_check(&input, &attrs, &krate, cost);
+
+    let params = generics::type_and_const_params(generics);
+    let mut light_clone_predicates = predicates.clone();
+    let mut clone_predicates = predicates;
+
     if let Data::Union(_) = &input.data {
-        return syn::Error::new_spanned(
-            &input.ident,
-            "LightClone derive is not supported for unions.",
-        )
-        .to_compile_error()
-        .into();
+        // Unions are cloned bitwise, so they are light exactly when they are Copy.
+        // Non-generic unions are asserted in `COST` for a clearer error.
+        if !params.is_empty() {
+            light_clone_predicates.push(syn::parse_quote!(Self: ::core::marker::Copy));
+        }
+        // Like `#[derive(Clone)]` on a union, the generated clone needs Copy params
+        for param in generics.type_params() {
+            let ident = &param.ident;
+            clone_predicates.push(syn::parse_quote!(#ident: ::core::marker::Copy));
+        }
+    } else if !attrs.derive_clone && !params.is_empty() {
+        // A generic type with a separate Clone impl may need more than the field bounds
+        // to be Clone (e.g. `#[derive(Clone)]` requires `T: Clone`), so require it here
+        light_clone_predicates.push(syn::parse_quote!(Self: ::core::clone::Clone));
     }
 
-    // Collect field types for where clause bounds
-    let field_types = collect_field_types(&input.data);
+    let (cost, cost_check) = build_eager_cost(&input, &krate, cost);
 
-    // Build the where clause with LightClone bounds
-    let where_clause =
-        build_where_clause(input.generics.where_clause.as_ref(), &field_types, generics);
+    let light_clone_where_clause = build_where_clause(&light_clone_predicates);
+    let where_clause = build_where_clause(&clone_predicates);
 
-    // Generate the impl - empty body uses the default implementation
+    // Generate the impl - `light_clone()` uses the default implementation, and `COST`
+    // doubles as the per-field assertions
     let light_clone_impl = quote! {
-        impl #impl_generics light_clone::LightClone for #name #ty_generics #where_clause {}
+        impl #impl_generics #krate::LightClone for #name #ty_generics #light_clone_where_clause {
+            const COST: #krate::CloneCost = #cost;
+        }
+    };
+
+    // Optionally generate a Clone impl that light-clones every field
+    let clone_impl = if attrs.derive_clone {
+        let body = build_clone_body(&input.data, &krate);
+        quote! {
+            impl #impl_generics ::core::clone::Clone for #name #ty_generics #where_clause {
+                #[inline]
+                fn clone(&self) -> Self {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {}
     };
 
-    light_clone_impl.into()
+    quote! {
+        #cost_check
+        #light_clone_impl
+        #clone_impl
+    }
+    .into()
 }
@@ -0,0 +1,451 @@
+use crate::attrs::{MirrorAttrs, MirrorField};
+use crate::{build_where_clause, crate_path, generics};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Ident, Member, Path, WherePredicate};
+
+/// A field of the deriving type together with its mapping to the twin.
+struct MirroredField<'a> {
+    field: &'a Field,
+    /// Position among all fields of the struct or variant.
+    index: usize,
+    /// Position among the fields kept in the twin, `None` for skipped fields.
+    twin_index: Option<usize>,
+    /// Whether the field type mentions a generic parameter of the deriving type.
+    generic: bool,
+    mapping: MirrorField,
+}
+
+impl MirroredField<'_> {
+    fn member(&self) -> Member {
+        member(self.field, self.index)
+    }
+
+    fn binding(&self) -> Ident {
+        format_ident!("__{}", self.index)
+    }
+
+    /// The field's type in the twin.
+    fn twin_type(&self, krate: &Path) -> TokenStream2 {
+        let ty = &self.field.ty;
+        match &self.mapping {
+            MirrorField::Convert => {
+                let krate = crate_path::respan(krate, ty.span());
+                quote_spanned! {ty.span()=> <#ty as #krate::IntoLight>::Light }
+            }
+            MirrorField::Keep => ty.to_token_stream(),
+            MirrorField::Into(target) => target.to_token_stream(),
+            MirrorField::Skip => unreachable!("skipped fields are not part of the twin"),
+        }
+    }
+
+    /// Converts the field's binding from the deriving type into the twin.
+    fn forward_expr(&self, krate: &Path) -> TokenStream2 {
+        let ty = &self.field.ty;
+        let binding = self.binding();
+        let krate = crate_path::respan(krate, ty.span());
+        match &self.mapping {
+            MirrorField::Convert => quote_spanned! {ty.span()=>
+                #krate::IntoLight::into_light(#binding)
+            },
+            MirrorField::Keep => quote! { #binding },
+            MirrorField::Into(_) => quote_spanned! {ty.span()=>
+                ::core::convert::Into::into(#binding)
+            },
+            MirrorField::Skip => unreachable!("skipped fields are not part of the twin"),
+        }
+    }
+
+    /// Converts the field's binding, a reference into the twin, back into the field.
+    fn reverse_expr(&self, krate: &Path) -> TokenStream2 {
+        let ty = &self.field.ty;
+        let binding = self.binding();
+        let krate = crate_path::respan(krate, ty.span());
+        match &self.mapping {
+            MirrorField::Convert => quote_spanned! {ty.span()=>
+                #krate::FromLight::from_light(#binding)
+            },
+            MirrorField::Keep => quote_spanned! {ty.span()=>
+                #krate::LightClone::light_clone(#binding)
+            },
+            MirrorField::Into(_) => quote_spanned! {ty.span()=>
+                ::core::convert::Into::into(#krate::LightClone::light_clone(#binding))
+            },
+            MirrorField::Skip => quote_spanned! {ty.span()=>
+                ::core::default::Default::default()
+            },
+        }
+    }
+}
+
+/// A struct or enum variant, with the path used to build and match it.
+struct Shape<'a> {
+    /// `Name` for structs, `Name::Variant` for enum variants.
+    path: TokenStream2,
+    /// The same path in the twin.
+    twin_path: TokenStream2,
+    /// The variant's own attributes, `None` for structs.
+    variant_attrs: Option<&'a [Attribute]>,
+    variant_ident: Option<&'a Ident>,
+    fields: &'a Fields,
+    mirrored: Vec<MirroredField<'a>>,
+}
+
+/// Expands `#[derive(LightMirror)]` into the light twin and the conversions between them.
+///
+/// Every field is mapped to `<T as IntoLight>::Light` unless a `#[light_mirror(...)]`
+/// field attribute says otherwise. The twin derives `LightClone` with `derive_clone`, so
+/// its `Clone` impl needs no bounds beyond the field types. Field types that depend on a
+/// generic parameter become where clause bounds, separately for each direction.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = MirrorAttrs::from_attrs(&input.attrs)?;
+    let krate = crate_path::resolve(attrs.crate_path.as_ref());
+    let name = &input.ident;
+    let twin = attrs
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Light", name));
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let params = generics::type_and_const_params(generics);
+
+    let shapes = collect_shapes(input, &twin, &params)?;
+
+    // Bounds for the twin's definition and for each direction of the conversion
+    let own: Vec<WherePredicate> = generics
+        .where_clause
+        .as_ref()
+        .map(|w| w.predicates.iter().cloned().collect())
+        .unwrap_or_default();
+    let mut definition = own.clone();
+    let mut forward = own.clone();
+    let mut reverse = own;
+    for mirrored in shapes.iter().flat_map(|shape| &shape.mirrored) {
+        let ty = &mirrored.field.ty;
+        let generic = mirrored.generic;
+        let krate = crate_path::respan(&krate, ty.span());
+        match &mirrored.mapping {
+            MirrorField::Convert if generic => {
+                let into: WherePredicate =
+                    syn::parse_quote_spanned!(ty.span()=> #ty: #krate::IntoLight);
+                definition.push(into.clone());
+                forward.push(into);
+                reverse.push(syn::parse_quote_spanned!(ty.span()=> #ty: #krate::FromLight));
+            }
+            MirrorField::Convert => {
+                // A field without a light equivalent is reported by the twin's field
+                // type. The unused lifetime keeps these bounds from being rejected as
+                // trivially false, so the conversion impls don't report it again.
+                forward.push(syn::parse_quote_spanned!(ty.span()=>
+                    for<'__light_clone> #ty: #krate::IntoLight
+                ));
+                reverse.push(syn::parse_quote_spanned!(ty.span()=>
+                    for<'__light_clone> #ty: #krate::FromLight
+                ));
+            }
+            MirrorField::Keep if generic => {
+                reverse.push(syn::parse_quote_spanned!(ty.span()=> #ty: #krate::LightClone));
+            }
+            MirrorField::Into(target) if generic || generics::mentions_params(target, &params) => {
+                forward.push(syn::parse_quote_spanned!(ty.span()=>
+                    #ty: ::core::convert::Into<#target>
+                ));
+                reverse.push(syn::parse_quote_spanned!(ty.span()=>
+                    #target: #krate::LightClone + ::core::convert::Into<#ty>
+                ));
+            }
+            MirrorField::Skip if generic => {
+                reverse.push(syn::parse_quote_spanned!(ty.span()=>
+                    #ty: ::core::default::Default
+                ));
+            }
+            _ => {}
+        }
+    }
+    let twin_light_clone: WherePredicate =
+        syn::parse_quote!(for<'__light_clone> #twin #ty_generics: #krate::LightClone);
+    let mut into_light = forward.clone();
+    into_light.push(twin_light_clone);
+    let mut from_light = into_light.clone();
+    from_light.extend(reverse.iter().cloned());
+
+    let definition = build_where_clause(&dedup(definition));
+    let forward = build_where_clause(&dedup(forward));
+    let reverse = build_where_clause(&dedup(reverse));
+    let into_light = build_where_clause(&dedup(into_light));
+    let from_light = build_where_clause(&dedup(from_light));
+
+    let body = twin_body(input, &twin, &shapes, &krate, &definition);
+    let forward_arms = shapes.iter().map(|shape| forward_arm(shape, &krate));
+    let reverse_arms = shapes.iter().map(|shape| reverse_arm(shape, &krate));
+    let (forward_match, reverse_match) = if shapes.is_empty() {
+        (quote! { match value {} }, quote! { match *light {} })
+    } else {
+        (
+            quote! { match value { #(#forward_arms)* } },
+            quote! { match light { #(#reverse_arms)* } },
+        )
+    };
+
+    let doc = format!("Light twin of [`{name}`], generated by `#[derive(LightMirror)]`.");
+    let derives = &attrs.derives;
+    let crate_attr = attrs.crate_path.as_ref().map(|path| {
+        let path = path.to_token_stream().to_string().replace(' ', "");
+        quote! { #[light_clone(crate = #path)] }
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#krate::LightClone #(, #derives)*)]
+        #[light_clone(derive_clone)]
+        #crate_attr
+        #vis #body
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics>
+            for #twin #ty_generics #forward
+        {
+            fn from(value: #name #ty_generics) -> Self {
+                #forward_match
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<&#twin #ty_generics>
+            for #name #ty_generics #reverse
+        {
+            fn from(light: &#twin #ty_generics) -> Self {
+                #reverse_match
+            }
+        }
+
+        impl #impl_generics #krate::IntoLight for #name #ty_generics #into_light {
+            type Light = #twin #ty_generics;
+
+            fn into_light(self) -> Self::Light {
+                ::core::convert::From::from(self)
+            }
+        }
+
+        impl #impl_generics #krate::FromLight for #name #ty_generics #from_light {
+            fn from_light(light: &Self::Light) -> Self {
+                ::core::convert::From::from(light)
+            }
+        }
+    })
+}
+
+/// Collects the struct or the enum variants with the mapping of every field.
+fn collect_shapes<'a>(
+    input: &'a DeriveInput,
+    twin: &Ident,
+    params: &[Ident],
+) -> syn::Result<Vec<Shape<'a>>> {
+    let name = &input.ident;
+    let shape = |path, twin_path, variant: Option<&'a syn::Variant>, fields: &'a Fields| {
+        let mut twin_index = 0;
+        let mirrored = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let mapping = MirrorField::from_attrs(&field.attrs)?;
+                let kept = !matches!(mapping, MirrorField::Skip);
+                let mirrored = MirroredField {
+                    field,
+                    index,
+                    twin_index: kept.then_some(twin_index),
+                    generic: generics::mentions_params(&field.ty, params),
+                    mapping,
+                };
+                twin_index += usize::from(kept);
+                Ok(mirrored)
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok(Shape {
+            path,
+            twin_path,
+            variant_attrs: variant.map(|variant| variant.attrs.as_slice()),
+            variant_ident: variant.map(|variant| &variant.ident),
+            fields,
+            mirrored,
+        })
+    };
+
+    match &input.data {
+        Data::Struct(data_struct) => Ok(vec![shape(
+            quote! { #name },
+            quote! { #twin },
+            None,
+            &data_struct.fields,
+        )?]),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                shape(
+                    quote! { #name::#ident },
+                    quote! { #twin::#ident },
+                    Some(variant),
+                    &variant.fields,
+                )
+            })
+            .collect(),
+        Data::Union(data_union) => Err(syn::Error::new(
+            data_union.union_token.span(),
+            "`LightMirror` cannot be derived for unions",
+        )),
+    }
+}
+
+/// Builds the twin's item definition, after its visibility.
+fn twin_body(
+    input: &DeriveInput,
+    twin: &Ident,
+    shapes: &[Shape<'_>],
+    krate: &Path,
+    where_clause: &TokenStream2,
+) -> TokenStream2 {
+    let generics = &input.generics;
+
+    match &input.data {
+        Data::Struct(_) => {
+            let shape = &shapes[0];
+            let fields = twin_fields(shape, krate);
+            match shape.fields {
+                Fields::Named(_) => quote! {
+                    struct #twin #generics #where_clause { #fields }
+                },
+                Fields::Unnamed(_) => quote! {
+                    struct #twin #generics (#fields) #where_clause;
+                },
+                Fields::Unit => quote! {
+                    struct #twin #generics #where_clause;
+                },
+            }
+        }
+        _ => {
+            let variants = shapes.iter().map(|shape| {
+                let attrs = docs(shape.variant_attrs.unwrap_or_default());
+                let ident = shape.variant_ident;
+                let fields = twin_fields(shape, krate);
+                match shape.fields {
+                    Fields::Named(_) => quote! { #(#attrs)* #ident { #fields } },
+                    Fields::Unnamed(_) => quote! { #(#attrs)* #ident(#fields) },
+                    Fields::Unit => quote! { #(#attrs)* #ident },
+                }
+            });
+            quote! {
+                enum #twin #generics #where_clause { #(#variants,)* }
+            }
+        }
+    }
+}
+
+/// Builds the comma-separated field list of a twin struct or variant.
+fn twin_fields(shape: &Shape<'_>, krate: &Path) -> TokenStream2 {
+    let fields = shape
+        .mirrored
+        .iter()
+        .filter(|mirrored| mirrored.twin_index.is_some())
+        .map(|mirrored| {
+            let field = mirrored.field;
+            let attrs = docs(&field.attrs);
+            let vis = &field.vis;
+            let ty = mirrored.twin_type(krate);
+            let bound = match mirrored.mapping {
+                // The light type is always `LightClone`, so there is nothing to assert
+                MirrorField::Convert if mirrored.generic => {
+                    Some(quote! { #[light_clone(bound = "")] })
+                }
+                // Keeps the twin's `Clone` and `LightClone` impls from reporting a field
+                // without a light equivalent again; the twin itself has no where clause,
+                // so its `COST` is still checked where it is declared
+                MirrorField::Convert => {
+                    let ty = &field.ty;
+                    let bound = quote! { for<'__light_clone> #ty: #krate::IntoLight };
+                    let bound = bound.to_string();
+                    Some(quote! { #[light_clone(bound = #bound)] })
+                }
+                _ => None,
+            };
+            match &field.ident {
+                Some(ident) => quote! { #(#attrs)* #bound #vis #ident: #ty },
+                None => quote! { #(#attrs)* #bound #vis #ty },
+            }
+        });
+    quote! { #(#fields),* }
+}
+
+/// Builds the match arm converting one struct or variant into the twin.
+///
+/// Both sides use braced syntax, which also works for tuple and unit shapes, so fields
+/// are addressed by name or index alike.
+fn forward_arm(shape: &Shape<'_>, krate: &Path) -> TokenStream2 {
+    let path = &shape.path;
+    let twin_path = &shape.twin_path;
+    let kept: Vec<_> = shape
+        .mirrored
+        .iter()
+        .filter(|mirrored| mirrored.twin_index.is_some())
+        .collect();
+    let members = kept.iter().map(|mirrored| mirrored.member());
+    let bindings = kept.iter().map(|mirrored| mirrored.binding());
+    let fields = kept.iter().map(|mirrored| {
+        let member = twin_member(mirrored);
+        let value = mirrored.forward_expr(krate);
+        quote! { #member: #value }
+    });
+    quote! {
+        #path { #(#members: #bindings,)* .. } => #twin_path { #(#fields),* },
+    }
+}
+
+/// Builds the match arm converting a reference to one twin struct or variant back.
+fn reverse_arm(shape: &Shape<'_>, krate: &Path) -> TokenStream2 {
+    let path = &shape.path;
+    let twin_path = &shape.twin_path;
+    let kept: Vec<_> = shape
+        .mirrored
+        .iter()
+        .filter(|mirrored| mirrored.twin_index.is_some())
+        .collect();
+    let members = kept.iter().map(|mirrored| twin_member(mirrored));
+    let bindings = kept.iter().map(|mirrored| mirrored.binding());
+    let fields = shape.mirrored.iter().map(|mirrored| {
+        let member = mirrored.member();
+        let value = mirrored.reverse_expr(krate);
+        quote! { #member: #value }
+    });
+    quote! {
+        #twin_path { #(#members: #bindings,)* .. } => #path { #(#fields),* },
+    }
+}
+
+/// Returns the field's name, or its index in the twin for tuple fields.
+fn twin_member(mirrored: &MirroredField<'_>) -> Member {
+    let index = mirrored
+        .twin_index
+        .expect("only kept fields have a twin member");
+    member(mirrored.field, index)
+}
+
+fn member(field: &Field, index: usize) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(syn::Index::from(index)),
+    }
+}
+
+/// Returns the doc comments among `attrs`, which carry over to the twin.
+fn docs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("doc"))
+}
+
+/// Removes repeated predicates, e.g. from fields of the same type.
+fn dedup(mut predicates: Vec<WherePredicate>) -> Vec<WherePredicate> {
+    let mut seen = HashSet::new();
+    predicates.retain(|predicate| seen.insert(predicate.to_token_stream().to_string()));
+    predicates
+}
@@ -0,0 +1,48 @@
+use crate::attrs::{BoundTarget, ContainerAttrs, FieldAttrs};
+use crate::{build_where_clause, collect_fields, crate_path, diagnostics, generics, infer_bounds};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+/// Expands `#[derive(LightCloneSync)]`.
+///
+/// Field types that depend on a generic parameter become `LightCloneSync` bounds on the
+/// impl, and concrete field types are asserted one by one, spanned on the field. The
+/// derive doesn't implement `LightClone` itself, so generic impls also require
+/// `Self: LightClone`.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::from_attrs(&input.attrs)?;
+    let krate = crate_path::resolve(attrs.crate_path.as_ref());
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let params = generics::type_and_const_params(&input.generics);
+
+    let bound_trait = format_ident!("LightCloneSync");
+    let mut predicates = infer_bounds(input, &attrs, &krate, &bound_trait, BoundTarget::Sync)?;
+    if !params.is_empty() {
+        predicates.push(syn::parse_quote!(Self: #krate::LightClone));
+    } else {
+        // A non-thread-safe field already fails its assertion; without this bound the
+        // impl would report the same field again through the `Send + Sync` supertraits.
+        // The unused lifetime keeps the bound from being rejected as trivially false.
+        predicates.push(syn::parse_quote!(
+            for<'__light_clone> Self: ::core::marker::Send + ::core::marker::Sync
+        ));
+    }
+
+    let mut assertions = Vec::new();
+    for field in collect_fields(&input.data) {
+        let field_attrs = FieldAttrs::from_attrs(&field.attrs)?;
+        let bound = field_attrs.bound.get(BoundTarget::Sync);
+        if bound.is_none() && !generics::mentions_params(&field.ty, &params) {
+            assertions.push(diagnostics::sync_field_assertion(&field.ty, &krate));
+        }
+    }
+
+    let where_clause = build_where_clause(&predicates);
+
+    Ok(quote! {
+        #(#assertions)*
+        impl #impl_generics #krate::LightCloneSync for #name #ty_generics #where_clause {}
+    })
+}
@@ -0,0 +1,3 @@
+//! Test-only crate that depends on `light_clone` under the name `cheap_clone`.
+//!
+//! The derive macros must resolve the renamed crate without a `crate = "..."` attribute.
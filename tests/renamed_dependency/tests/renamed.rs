@@ -0,0 +1,38 @@
+use cheap_clone::LightClone;
+use std::sync::Arc;
+
+#[derive(Clone, LightClone)]
+struct Config {
+    name: Arc<str>,
+    port: u16,
+}
+
+#[derive(LightClone)]
+#[light_clone(derive_clone)]
+enum Message<T> {
+    Ping,
+    Payload(Arc<T>),
+}
+
+#[test]
+fn test_derive_with_renamed_dependency() {
+    let config = Config {
+        name: Arc::from("renamed"),
+        port: 8080,
+    };
+
+    let cloned = config.light_clone();
+    assert_eq!(cloned.port, 8080);
+    assert!(Arc::ptr_eq(&config.name, &cloned.name));
+}
+
+#[test]
+fn test_derive_clone_with_renamed_dependency() {
+    let payload = Arc::new(String::from("payload"));
+    let message = Message::Payload(Arc::clone(&payload));
+
+    match message.clone() {
+        Message::Payload(cloned) => assert!(Arc::ptr_eq(&cloned, &payload)),
+        Message::Ping => panic!("Expected Payload variant"),
+    }
+}